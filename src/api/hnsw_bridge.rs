@@ -0,0 +1,44 @@
+use numpy::PyReadonlyArray1;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::vdb::adapter::DocId;
+use crate::vdb::hnsw::{self, HnswIndex as CoreHnswIndex};
+
+/// Python에 노출되는 인메모리 HNSW 벡터 인덱스다.
+///
+/// 매 질의마다 Python 콜백을 왕복하는 대신, 벡터를 Rust 프로세스 안에 직접
+/// 저장/검색하려는 호출자를 위한 `VdbAdapter` 구현체다.
+/// `SearchEngine::search_with_index`/`search_text_with_index`에 넘겨 사용한다.
+#[pyclass(name = "HnswIndex")]
+pub struct PyHnswIndex {
+    pub(crate) inner: CoreHnswIndex,
+}
+
+#[pymethods]
+impl PyHnswIndex {
+    /// `M`, `ef_construction`, `ef`를 지정해 빈 인덱스를 만든다.
+    #[new]
+    #[pyo3(signature = (
+        dim,
+        m = hnsw::DEFAULT_M,
+        ef_construction = hnsw::DEFAULT_EF_CONSTRUCTION,
+        ef = hnsw::DEFAULT_EF,
+    ))]
+    pub fn new(dim: usize, m: usize, ef_construction: usize, ef: usize) -> Self {
+        Self {
+            inner: CoreHnswIndex::new(dim, m, ef_construction, ef),
+        }
+    }
+
+    /// 정규화한 벡터를 인덱스에 삽입한다.
+    pub fn insert(&mut self, id: u32, vector: PyReadonlyArray1<'_, f32>) -> PyResult<()> {
+        let vector_slice = vector.as_slice().map_err(|_| {
+            PyValueError::new_err("vector는 contiguous float32 1D 배열이어야 합니다")
+        })?;
+
+        self.inner
+            .insert(id as DocId, vector_slice.to_vec())
+            .map_err(PyValueError::new_err)
+    }
+}
@@ -1,33 +1,273 @@
+use std::sync::Mutex;
+
 use numpy::PyReadonlyArray1;
 use pyo3::exceptions::{PyRuntimeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::types::PyAny;
 
+use crate::api::hnsw_bridge::PyHnswIndex;
 use crate::core::pipeline;
+use crate::math::linalg;
 use crate::vdb::adapter::CallbackVdb;
+use crate::vdb::query;
 
 #[pyclass(name = "SearchEngine")]
-pub struct PySearchEngine;
+pub struct PySearchEngine {
+    /// 검증 기준이 되는 임베딩 차원이다.
+    ///
+    /// 생성 시 `expected_dim`이 주어지면 실제 벡터 인덱스의 차원을 알고 있는
+    /// 스키마 검증으로 동작한다. 주어지지 않으면 첫 `search_text` 호출에서
+    /// 반환된 차원을 캐시해 이후 호출과의 자기 일관성만 검증한다 — 이는
+    /// 처음부터 일관되게 잘못된 임베더 차원은 잡아내지 못한다.
+    embed_dim: Mutex<Option<usize>>,
+}
 
 #[pymethods]
 impl PySearchEngine {
+    /// `expected_dim`을 주면 실제 벡터 인덱스 차원과 대조하는 스키마 검증이 되고,
+    /// 생략하면(`None`) 첫 호출 차원을 기준으로 한 자기 일관성 검증으로 동작한다.
     #[new]
-    pub fn new() -> Self {
-        Self
+    #[pyo3(signature = (expected_dim = None))]
+    pub fn new(expected_dim: Option<usize>) -> Self {
+        Self {
+            embed_dim: Mutex::new(expected_dim),
+        }
     }
 
+    #[pyo3(signature = (
+        query,
+        top_k,
+        search_fn,
+        semantic_ratio = 1.0,
+        keyword_terms = None,
+        rrf_k = query::RRF_K,
+        survivor_count = query::SURVIVOR_COUNT,
+        explain = false,
+    ))]
     pub fn search(
         &self,
         query: PyReadonlyArray1<'_, f32>,
         top_k: usize,
         search_fn: Py<PyAny>,
-    ) -> PyResult<(Vec<u32>, Vec<f32>)> {
+        semantic_ratio: f32,
+        keyword_terms: Option<String>,
+        rrf_k: f32,
+        survivor_count: usize,
+        explain: bool,
+    ) -> PyResult<(Vec<u32>, Vec<f32>, Option<String>)> {
         let query_slice = query
             .as_slice()
             .map_err(|_| PyValueError::new_err("query는 contiguous float32 1D 배열이어야 합니다"))?;
 
         let callback_adapter = CallbackVdb::new(search_fn, query_slice.len());
-        pipeline::execute_search(&callback_adapter, query_slice, top_k)
-            .map_err(PyRuntimeError::new_err)
+        let (ids, scores, explanations) = pipeline::execute_search(
+            &callback_adapter,
+            query_slice,
+            top_k,
+            semantic_ratio,
+            keyword_terms.as_deref(),
+            rrf_k,
+            survivor_count,
+            explain,
+        )
+        .map_err(PyRuntimeError::new_err)?;
+
+        let explain_json = explanations
+            .map(|details| {
+                serde_json::to_string(&details).map_err(|error| {
+                    PyRuntimeError::new_err(format!("ScoreDetails 직렬화에 실패했습니다: {}", error))
+                })
+            })
+            .transpose()?;
+
+        Ok((ids, scores, explain_json))
+    }
+
+    /// Python 콜백 왕복 없이, 프로세스 내 `HnswIndex`를 VDB 어댑터로 써서 검색한다.
+    #[pyo3(signature = (
+        query,
+        top_k,
+        index,
+        semantic_ratio = 1.0,
+        keyword_terms = None,
+        rrf_k = query::RRF_K,
+        survivor_count = query::SURVIVOR_COUNT,
+        explain = false,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn search_with_index(
+        &self,
+        query: PyReadonlyArray1<'_, f32>,
+        top_k: usize,
+        index: &PyHnswIndex,
+        semantic_ratio: f32,
+        keyword_terms: Option<String>,
+        rrf_k: f32,
+        survivor_count: usize,
+        explain: bool,
+    ) -> PyResult<(Vec<u32>, Vec<f32>, Option<String>)> {
+        let query_slice = query
+            .as_slice()
+            .map_err(|_| PyValueError::new_err("query는 contiguous float32 1D 배열이어야 합니다"))?;
+
+        let (ids, scores, explanations) = pipeline::execute_search(
+            &index.inner,
+            query_slice,
+            top_k,
+            semantic_ratio,
+            keyword_terms.as_deref(),
+            rrf_k,
+            survivor_count,
+            explain,
+        )
+        .map_err(PyRuntimeError::new_err)?;
+
+        let explain_json = explanations
+            .map(|details| {
+                serde_json::to_string(&details).map_err(|error| {
+                    PyRuntimeError::new_err(format!("ScoreDetails 직렬화에 실패했습니다: {}", error))
+                })
+            })
+            .transpose()?;
+
+        Ok((ids, scores, explain_json))
+    }
+
+    /// 원문 텍스트를 임베더 콜백으로 벡터화해 검색한다.
+    ///
+    /// `embed_fn`은 텍스트를 받아 `float32` 벡터를 돌려주는 콜백이며, 반환 벡터는
+    /// `linalg::normalize_in_place`로 정규화된 뒤 기존 파이프라인에 투입된다.
+    /// 모든 Python 호출자가 쿼리 임베딩을 직접 구현할 필요를 없앤다.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (
+        text,
+        top_k,
+        embed_fn,
+        search_fn,
+        semantic_ratio = 1.0,
+        keyword_terms = None,
+        rrf_k = query::RRF_K,
+        survivor_count = query::SURVIVOR_COUNT,
+    ))]
+    pub fn search_text(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        top_k: usize,
+        embed_fn: Py<PyAny>,
+        search_fn: Py<PyAny>,
+        semantic_ratio: f32,
+        keyword_terms: Option<String>,
+        rrf_k: f32,
+        survivor_count: usize,
+    ) -> PyResult<(Vec<u32>, Vec<f32>)> {
+        let query_vec = self.embed_and_validate(py, text, embed_fn)?;
+
+        let callback_adapter = CallbackVdb::new(search_fn, query_vec.len());
+        let (ids, scores, _explanations) = pipeline::execute_search(
+            &callback_adapter,
+            &query_vec,
+            top_k,
+            semantic_ratio,
+            keyword_terms.as_deref(),
+            rrf_k,
+            survivor_count,
+            false,
+        )
+        .map_err(PyRuntimeError::new_err)?;
+
+        Ok((ids, scores))
+    }
+
+    /// Python 콜백 왕복 없이, 프로세스 내 `HnswIndex`를 VDB 어댑터로 써서
+    /// 원문 텍스트 검색을 수행한다.
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (
+        text,
+        top_k,
+        embed_fn,
+        index,
+        semantic_ratio = 1.0,
+        keyword_terms = None,
+        rrf_k = query::RRF_K,
+        survivor_count = query::SURVIVOR_COUNT,
+    ))]
+    pub fn search_text_with_index(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        top_k: usize,
+        embed_fn: Py<PyAny>,
+        index: &PyHnswIndex,
+        semantic_ratio: f32,
+        keyword_terms: Option<String>,
+        rrf_k: f32,
+        survivor_count: usize,
+    ) -> PyResult<(Vec<u32>, Vec<f32>)> {
+        let query_vec = self.embed_and_validate(py, text, embed_fn)?;
+
+        let (ids, scores, _explanations) = pipeline::execute_search(
+            &index.inner,
+            &query_vec,
+            top_k,
+            semantic_ratio,
+            keyword_terms.as_deref(),
+            rrf_k,
+            survivor_count,
+            false,
+        )
+        .map_err(PyRuntimeError::new_err)?;
+
+        Ok((ids, scores))
+    }
+}
+
+impl PySearchEngine {
+    /// `embed_fn`으로 텍스트를 벡터화하고, 기대 차원과 대조한 뒤 정규화한다.
+    ///
+    /// `search_text`/`search_text_with_index`가 공유하는 임베딩 전처리다.
+    fn embed_and_validate(
+        &self,
+        py: Python<'_>,
+        text: &str,
+        embed_fn: Py<PyAny>,
+    ) -> PyResult<Vec<f32>> {
+        if text.trim().is_empty() {
+            return Err(PyValueError::new_err("text는 비어 있을 수 없습니다"));
+        }
+
+        let embedded = embed_fn.call1(py, (text,)).map_err(|error| {
+            PyRuntimeError::new_err(format!("embed_fn 호출에 실패했습니다: {}", error))
+        })?;
+        let mut query_vec: Vec<f32> = embedded.extract(py).map_err(|error| {
+            PyRuntimeError::new_err(format!(
+                "embed_fn 반환 형식이 올바르지 않습니다: expected=Vec<f32>, error={}",
+                error
+            ))
+        })?;
+
+        {
+            let mut cached = self
+                .embed_dim
+                .lock()
+                .map_err(|_| PyRuntimeError::new_err("임베더 차원 캐시 잠금을 획득할 수 없습니다"))?;
+            match *cached {
+                Some(dim) if dim != query_vec.len() => {
+                    return Err(PyValueError::new_err(format!(
+                        "임베더 차원이 기대 차원과 다릅니다: expected={}, actual={}",
+                        dim,
+                        query_vec.len()
+                    )));
+                }
+                None => *cached = Some(query_vec.len()),
+                _ => {}
+            }
+        }
+
+        if linalg::normalize_in_place(&mut query_vec).is_none() {
+            return Err(PyValueError::new_err("embed_fn이 0-벡터를 반환했습니다"));
+        }
+
+        Ok(query_vec)
     }
 }
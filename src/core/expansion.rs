@@ -1,6 +1,8 @@
 use hashbrown::HashMap;
+use metrics::histogram;
 use std::collections::HashSet;
 
+use crate::core::telemetry;
 use crate::math::{normalize, topk};
 use crate::vdb::adapter::{DocId, ScoredDoc, VdbAdapter};
 use crate::vdb::{fetch, query};
@@ -45,8 +47,10 @@ pub fn rerank_until_top_k(
     let mut prev_top_ids: Option<HashSet<DocId>> = None;
     let mut prev_top_score_sum: Option<f32> = None;
     let mut stable_rounds = 0usize;
+    let mut rounds_run = 0usize;
 
     for round in 1..=query::MAX_REFINEMENT_ROUNDS {
+        rounds_run = round;
         let limit = base_limit.saturating_mul(round);
         let round_hits = adapter.search(prf_query, limit)?;
         let round_hit_count = round_hits.len();
@@ -91,6 +95,9 @@ pub fn rerank_until_top_k(
         }
     }
 
+    histogram!(telemetry::RERANK_ROUNDS).record(rounds_run as f64);
+    histogram!(telemetry::RERANK_STABLE_ROUNDS).record(stable_rounds as f64);
+
     let mut reranked = merged.into_iter().collect::<Vec<ScoredDoc>>();
     topk::sort_desc_take(&mut reranked, top_k);
     Ok(reranked)
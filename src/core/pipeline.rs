@@ -1,13 +1,51 @@
-use crate::core::{expansion, voting};
-use crate::math::normalize;
-use crate::vdb::adapter::{ScoredDoc, VdbAdapter};
+use hashbrown::HashMap;
+use serde::Serialize;
+
+use crate::core::voting::{self, VoteClass, VoteRecord};
+use crate::core::expansion;
+use crate::math::{normalize, topk};
+use crate::vdb::adapter::{DocId, ScoredDoc, VdbAdapter};
 use crate::vdb::query;
 
+/// 한 문서가 특정 세그먼트에서 차지한 순위를 기술한다.
+#[derive(Debug, Clone, Serialize)]
+pub struct SegmentAppearance {
+    pub segment: usize,
+    pub rank: usize,
+}
+
+/// 반환된 문서가 왜 그 위치에 랭크됐는지 설명하는 구조체다.
+///
+/// Meilisearch의 ScoreDetails처럼 각 랭킹 규칙의 기여를 분해해 보여 준다.
+#[derive(Debug, Clone, Serialize)]
+pub struct ScoreDetails {
+    pub doc_id: DocId,
+    pub segments: Vec<SegmentAppearance>,
+    pub rank_score: f32,
+    pub best_score: f32,
+    pub vote_class: VoteClass,
+    pub survived: bool,
+    pub final_score: f32,
+}
+
+/// 벡터 채널 실행 결과와 설명에 필요한 중간 산출물을 함께 담는다.
+struct VectorChannelOutcome {
+    ranked: Vec<ScoredDoc>,
+    segment_results: Vec<Vec<ScoredDoc>>,
+    vote_records: Vec<VoteRecord>,
+    survivor_ids: Vec<DocId>,
+}
+
 pub fn execute_search(
     adapter: &dyn VdbAdapter,
     query: &[f32],
     top_k: usize,
-) -> Result<(Vec<u32>, Vec<f32>), String> {
+    semantic_ratio: f32,
+    keyword_terms: Option<&str>,
+    rrf_k: f32,
+    survivor_count: usize,
+    explain: bool,
+) -> Result<(Vec<u32>, Vec<f32>, Option<Vec<ScoreDetails>>), String> {
     if top_k == 0 {
         return Err("top_k는 1 이상이어야 합니다".to_string());
     }
@@ -21,7 +59,104 @@ pub fn execute_search(
             query.len()
         ));
     }
+    if !(0.0..=1.0).contains(&semantic_ratio) {
+        return Err("semantic_ratio는 [0, 1] 범위여야 합니다".to_string());
+    }
+
+    if survivor_count == 0 {
+        return Err("survivor_count는 1 이상이어야 합니다".to_string());
+    }
+
+    let outcome = vector_channel(adapter, query, top_k, rrf_k, survivor_count)?;
+
+    // 텍스트 채널이 없거나 순수 벡터 비중이면 기존 동작을 그대로 유지한다.
+    let keyword_active = semantic_ratio < 1.0
+        && keyword_terms
+            .map(|terms| !terms.trim().is_empty())
+            .unwrap_or(false);
+
+    let final_ranked = if keyword_active {
+        let terms = keyword_terms.expect("keyword_active는 terms 존재를 보장합니다");
+        let keyword_ranked = adapter.keyword_search(terms, top_k)?;
+        fuse_channels(&outcome.ranked, &keyword_ranked, semantic_ratio, top_k)
+    } else {
+        outcome.ranked.clone()
+    };
+
+    if final_ranked.is_empty() {
+        return Err("최종 검색 결과가 비어 있습니다".to_string());
+    }
+
+    let explanations = if explain {
+        Some(build_explanations(&final_ranked, &outcome))
+    } else {
+        None
+    };
+
+    let (doc_ids, scores): (Vec<u32>, Vec<f32>) = final_ranked.into_iter().unzip();
+    Ok((doc_ids, scores, explanations))
+}
+
+/// 반환된 각 문서에 대해 세그먼트 등장/투표/생존/최종 점수를 설명으로 조립한다.
+fn build_explanations(
+    final_ranked: &[ScoredDoc],
+    outcome: &VectorChannelOutcome,
+) -> Vec<ScoreDetails> {
+    let vote_map = outcome
+        .vote_records
+        .iter()
+        .map(|record| (record.doc_id, record))
+        .collect::<HashMap<_, _>>();
+    let survivors = outcome
+        .survivor_ids
+        .iter()
+        .copied()
+        .collect::<std::collections::HashSet<_>>();
 
+    final_ranked
+        .iter()
+        .map(|(doc_id, final_score)| {
+            let segments = outcome
+                .segment_results
+                .iter()
+                .enumerate()
+                .filter_map(|(segment, hits)| {
+                    hits.iter()
+                        .position(|(hit_id, _)| hit_id == doc_id)
+                        .map(|rank| SegmentAppearance { segment, rank })
+                })
+                .collect::<Vec<_>>();
+
+            let (rank_score, best_score, vote_class) = match vote_map.get(doc_id) {
+                Some(record) => (
+                    record.rank_score,
+                    record.best_score,
+                    voting::classify_vote(record.votes),
+                ),
+                None => (0.0, 0.0, VoteClass::Noise),
+            };
+
+            ScoreDetails {
+                doc_id: *doc_id,
+                segments,
+                rank_score,
+                best_score,
+                vote_class,
+                survived: survivors.contains(doc_id),
+                final_score: *final_score,
+            }
+        })
+        .collect()
+}
+
+/// 세그먼트 벡터 파이프라인(투표 -> PRF -> 재랭킹)을 실행해 하나의 랭킹 리스트를 만든다.
+fn vector_channel(
+    adapter: &dyn VdbAdapter,
+    query: &[f32],
+    top_k: usize,
+    rrf_k: f32,
+    survivor_count: usize,
+) -> Result<VectorChannelOutcome, String> {
     let normalized_query = normalize::normalized_copy(query)
         .ok_or_else(|| "query 정규화에 실패했습니다 (0-벡터)".to_string())?;
 
@@ -32,19 +167,71 @@ pub fn execute_search(
         segment_results.push(adapter.search(&segment_query, query::SEGMENT_TOP_K)?);
     }
 
-    let vote_records = voting::merge_segment_results(&segment_results);
-    let survivor_ids = voting::select_survivor_ids(&vote_records, query::SURVIVOR_COUNT);
+    let vote_records = voting::merge_segment_results(&segment_results, rrf_k);
+    let survivor_ids = voting::select_survivor_ids(&vote_records, survivor_count);
     if survivor_ids.is_empty() {
         return Err("투표 규칙을 통과한 생존 후보가 없습니다".to_string());
     }
 
     let prf_query = expansion::build_prf_query(&normalized_query, &survivor_ids, adapter)?;
-    let final_ranked = expansion::rerank_until_top_k(adapter, &prf_query, top_k)?;
+    let ranked = expansion::rerank_until_top_k(adapter, &prf_query, top_k)?;
 
-    if final_ranked.is_empty() {
-        return Err("최종 검색 결과가 비어 있습니다".to_string());
+    Ok(VectorChannelOutcome {
+        ranked,
+        segment_results,
+        vote_records,
+        survivor_ids,
+    })
+}
+
+/// 벡터/키워드 채널 점수를 각각 min-max 정규화한 뒤 가중 합으로 결합한다.
+fn fuse_channels(
+    vector_ranked: &[ScoredDoc],
+    keyword_ranked: &[ScoredDoc],
+    semantic_ratio: f32,
+    top_k: usize,
+) -> Vec<ScoredDoc> {
+    let vector_scores = min_max_normalize(vector_ranked);
+    let keyword_scores = min_max_normalize(keyword_ranked);
+
+    let mut fused = HashMap::<DocId, f32>::new();
+    for (doc_id, vec_score) in &vector_scores {
+        *fused.entry(*doc_id).or_insert(0.0) += semantic_ratio * *vec_score;
+    }
+    for (doc_id, kw_score) in &keyword_scores {
+        *fused.entry(*doc_id).or_insert(0.0) += (1.0 - semantic_ratio) * *kw_score;
     }
 
-    let (doc_ids, scores): (Vec<u32>, Vec<f32>) = final_ranked.into_iter().unzip();
-    Ok((doc_ids, scores))
+    let mut ranked = fused.into_iter().collect::<Vec<ScoredDoc>>();
+    topk::sort_desc_take(&mut ranked, top_k);
+    ranked
+}
+
+/// 채널 점수를 [0, 1] 구간으로 min-max 정규화한다.
+///
+/// 모든 점수가 동일하면 랭킹 정보가 없으므로 1.0으로 둔다.
+fn min_max_normalize(ranked: &[ScoredDoc]) -> Vec<ScoredDoc> {
+    if ranked.is_empty() {
+        return Vec::new();
+    }
+
+    let mut min = f32::INFINITY;
+    let mut max = f32::NEG_INFINITY;
+    for (_, score) in ranked {
+        min = min.min(*score);
+        max = max.max(*score);
+    }
+
+    let span = max - min;
+    ranked
+        .iter()
+        .map(|(doc_id, score)| {
+            let normalized = if span <= f32::EPSILON {
+                1.0
+            } else {
+                (score - min) / span
+            };
+            (*doc_id, normalized)
+        })
+        .collect()
 }
@@ -0,0 +1,27 @@
+// 목적:
+// - 검색 런타임 핫 패스의 metrics 계측 이름과 초기화 훅을 제공한다.
+//
+// 설명:
+// - PRF 리랭크 라운드/조기 종료 분포를 관측할 히스토그램 이름을 한곳에 모은다.
+// - 호스트 프로세스가 임의의 recorder를 등록할 수 있는 얇은 init 훅을 노출한다.
+//
+// 디자인 패턴:
+// - 파사드(Facade) + 상수 집약(Constant Aggregation).
+//
+// 참조:
+// - src/core/expansion.rs
+
+use metrics::Recorder;
+
+/// rerank_until_top_k에서 수행한 라운드 수 히스토그램.
+pub const RERANK_ROUNDS: &str = "rerank_rounds";
+/// rerank_until_top_k에서 도달한 조기 종료 안정 라운드 수 히스토그램.
+pub const RERANK_STABLE_ROUNDS: &str = "rerank_stable_rounds";
+
+/// 호스트 프로세스가 전역 metrics recorder를 등록하는 얇은 훅이다.
+pub fn init<R>(recorder: R) -> Result<(), metrics::SetRecorderError<R>>
+where
+    R: Recorder + Sync + 'static,
+{
+    metrics::set_global_recorder(recorder)
+}
@@ -1,8 +1,10 @@
 use hashbrown::HashMap;
+use serde::Serialize;
 
 use crate::vdb::adapter::{DocId, ScoredDoc};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
 pub enum VoteClass {
     Strong,
     Weak,
@@ -20,23 +22,51 @@ pub struct VoteRecord {
 #[derive(Debug, Clone, Copy)]
 struct VoteAggregate {
     votes: u8,
-    rank_score: f32,
     best_score: f32,
 }
 
-pub fn merge_segment_results(segment_results: &[Vec<ScoredDoc>]) -> Vec<VoteRecord> {
+/// Reciprocal Rank Fusion으로 여러 랭킹 리스트를 하나로 합친다.
+///
+/// `score(d) = Σ_lists 1/(k + rank_d)` (rank는 0-based)로 계산하며,
+/// 어떤 리스트에 등장하지 않는 문서는 그 리스트에서 점수를 받지 못한다.
+/// 세그먼트 융합과 하이브리드 채널 융합이 동일한 규칙을 공유하도록 하는
+/// 점수-리스트 비의존(list-agnostic) 융합기다.
+pub fn rrf_fuse(lists: &[Vec<ScoredDoc>], k: f32) -> Vec<ScoredDoc> {
+    let mut fused = HashMap::<DocId, f32>::new();
+
+    for list in lists {
+        for (rank, (doc_id, _)) in list.iter().enumerate() {
+            *fused.entry(*doc_id).or_insert(0.0) += 1.0f32 / (k + rank as f32);
+        }
+    }
+
+    let mut ranked = fused.into_iter().collect::<Vec<ScoredDoc>>();
+    ranked.sort_by(|left, right| {
+        right
+            .1
+            .partial_cmp(&left.1)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| left.0.cmp(&right.0))
+    });
+    ranked
+}
+
+pub fn merge_segment_results(segment_results: &[Vec<ScoredDoc>], k: f32) -> Vec<VoteRecord> {
+    // rank_score는 rrf_fuse가 계산하는 표준 RRF 합과 동일한 규칙을 공유한다.
+    let rank_scores = rrf_fuse(segment_results, k)
+        .into_iter()
+        .collect::<HashMap<DocId, f32>>();
+
     let mut aggregated = HashMap::<DocId, VoteAggregate>::new();
 
     for segment_result in segment_results {
-        for (rank, (doc_id, score)) in segment_result.iter().enumerate() {
-            let rank_score = 1.0f32 / (rank as f32 + 1.0);
+        for (doc_id, score) in segment_result.iter() {
             let entry = aggregated.entry(*doc_id).or_insert(VoteAggregate {
                 votes: 0,
-                rank_score: 0.0,
                 best_score: f32::NEG_INFINITY,
             });
+            // 투표 수는 리스트 멤버십(등장한 세그먼트 개수)에서 파생한다.
             entry.votes += 1;
-            entry.rank_score += rank_score;
             entry.best_score = entry.best_score.max(*score);
         }
     }
@@ -46,7 +76,7 @@ pub fn merge_segment_results(segment_results: &[Vec<ScoredDoc>]) -> Vec<VoteReco
         .map(|(doc_id, agg)| VoteRecord {
             doc_id,
             votes: agg.votes,
-            rank_score: agg.rank_score,
+            rank_score: rank_scores.get(&doc_id).copied().unwrap_or(0.0),
             best_score: agg.best_score,
         })
         .collect::<Vec<_>>();
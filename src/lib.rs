@@ -4,12 +4,14 @@ use pyo3::prelude::*;
 use pyo3::types::PyModule;
 
 pub mod api {
+    pub mod hnsw_bridge;
     pub mod search_engine;
 }
 
 pub mod core {
     pub mod expansion;
     pub mod pipeline;
+    pub mod telemetry;
     pub mod voting;
 }
 
@@ -22,13 +24,16 @@ pub mod math {
 pub mod vdb {
     pub mod adapter;
     pub mod fetch;
+    pub mod hnsw;
     pub mod query;
 }
 
+use api::hnsw_bridge::PyHnswIndex;
 use api::search_engine::PySearchEngine;
 
 #[pymodule]
 fn _svve_core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PySearchEngine>()?;
+    m.add_class::<PyHnswIndex>()?;
     Ok(())
 }
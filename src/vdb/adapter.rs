@@ -20,6 +20,15 @@ pub trait VdbAdapter: Send + Sync {
     fn dim(&self) -> usize;
     fn search(&self, query: &[f32], limit: usize) -> Result<Vec<ScoredDoc>, String>;
     fn fetch_vectors(&self, doc_ids: &[DocId]) -> Result<Vec<DocVector>, String>;
+
+    /// 어휘(키워드) 기반 검색 채널이다.
+    ///
+    /// 하이브리드 검색에서 벡터 채널과 결합할 렉시컬 결과를 돌려준다.
+    /// 키워드 채널을 지원하지 않는 어댑터는 빈 결과를 반환한다.
+    fn keyword_search(&self, terms: &str, limit: usize) -> Result<Vec<ScoredDoc>, String> {
+        let _ = (terms, limit);
+        Ok(Vec::new())
+    }
 }
 
 #[derive(Debug)]
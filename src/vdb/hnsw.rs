@@ -0,0 +1,344 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+
+use crate::math::linalg;
+use crate::vdb::adapter::{DocId, DocVector, ScoredDoc, VdbAdapter};
+
+/// 기본 HNSW 구성 파라미터다.
+pub const DEFAULT_M: usize = 16;
+pub const DEFAULT_EF_CONSTRUCTION: usize = 200;
+pub const DEFAULT_EF: usize = 64;
+
+/// total_cmp 기반으로 `BinaryHeap`에 담을 수 있는 f32 래퍼다.
+#[derive(Clone, Copy)]
+struct OrdF32(f32);
+
+impl PartialEq for OrdF32 {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.total_cmp(&other.0) == Ordering::Equal
+    }
+}
+impl Eq for OrdF32 {}
+impl PartialOrd for OrdF32 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for OrdF32 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+/// 결정적 난수 생성을 위한 SplitMix64 구현이다.
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// [0, 1) 범위의 f64를 반환한다.
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+struct HnswNode {
+    id: DocId,
+    vector: Vec<f32>,
+    /// 레이어별 이웃 노드 인덱스. `neighbors[layer]`.
+    neighbors: Vec<Vec<usize>>,
+}
+
+/// 메모리 내 HNSW 근접 그래프로 동작하는 `VdbAdapter` 구현이다.
+///
+/// 전체 검색 파이프라인을 Python 콜백 왕복 없이 Rust에서 실행하기 위한 네이티브
+/// 벡터 저장소다. 벡터는 삽입 시 정규화되어 보관되며, `fetch_vectors`가 PRF 단계에
+/// 그대로 제공한다. 점수는 정규화 벡터 간 `linalg::dot`으로 계산한다.
+pub struct HnswIndex {
+    dim: usize,
+    m: usize,
+    m0: usize,
+    ef_construction: usize,
+    ef: usize,
+    ml: f64,
+    nodes: Vec<HnswNode>,
+    id_to_index: HashMap<DocId, usize>,
+    entry_point: Option<usize>,
+    rng: SplitMix64,
+}
+
+impl HnswIndex {
+    /// `M`, `ef_construction`, `ef`를 지정해 빈 인덱스를 만든다.
+    pub fn new(dim: usize, m: usize, ef_construction: usize, ef: usize) -> Self {
+        let m = m.max(1);
+        Self {
+            dim,
+            m,
+            m0: m * 2,
+            ef_construction: ef_construction.max(m),
+            ef: ef.max(1),
+            ml: 1.0 / (m as f64).ln(),
+            nodes: Vec::new(),
+            id_to_index: HashMap::new(),
+            entry_point: None,
+            rng: SplitMix64::new(0x2545_F491_4F6C_DD1D),
+        }
+    }
+
+    /// 정규화한 벡터를 인덱스에 삽입한다.
+    pub fn insert(&mut self, id: DocId, vector: Vec<f32>) -> Result<(), String> {
+        if vector.len() != self.dim {
+            return Err(format!(
+                "삽입 벡터 차원이 일치하지 않습니다: expected={}, actual={}, id={}",
+                self.dim,
+                vector.len(),
+                id
+            ));
+        }
+
+        let mut normalized = vector;
+        if linalg::normalize_in_place(&mut normalized).is_none() {
+            return Err(format!("0-벡터는 삽입할 수 없습니다: id={}", id));
+        }
+
+        let level = self.random_level();
+        let node_index = self.nodes.len();
+        self.nodes.push(HnswNode {
+            id,
+            vector: normalized,
+            neighbors: vec![Vec::new(); level + 1],
+        });
+        self.id_to_index.insert(id, node_index);
+
+        let entry = match self.entry_point {
+            Some(entry) => entry,
+            None => {
+                self.entry_point = Some(node_index);
+                return Ok(());
+            }
+        };
+
+        let query = self.nodes[node_index].vector.clone();
+        let top = self.top_layer(entry);
+
+        // 진입점에서 l+1 레이어까지 탐욕적으로 하강한다.
+        let mut cursor = entry;
+        let mut layer = top;
+        while layer > level {
+            let nearest = self.search_layer(&query, &[cursor], 1, layer);
+            if let Some((_, idx)) = nearest.first() {
+                cursor = *idx;
+            }
+            layer -= 1;
+        }
+
+        // min(level, top)부터 0까지 각 레이어에서 후보 탐색 후 이웃을 연결한다.
+        let start = level.min(top);
+        for layer in (0..=start).rev() {
+            let m_max = if layer == 0 { self.m0 } else { self.m };
+            let candidates = self.search_layer(&query, &[cursor], self.ef_construction, layer);
+            let selected = self.select_neighbors(&query, &candidates, m_max);
+
+            self.nodes[node_index].neighbors[layer] = selected.clone();
+            for neighbor in selected {
+                self.nodes[neighbor].neighbors[layer].push(node_index);
+                self.prune_neighbors(neighbor, layer, m_max);
+            }
+
+            if let Some((_, idx)) = candidates.first() {
+                cursor = *idx;
+            }
+        }
+
+        // 새 노드가 더 높은 레이어를 가지면 진입점을 갱신한다.
+        if level > top {
+            self.entry_point = Some(node_index);
+        }
+
+        Ok(())
+    }
+
+    fn random_level(&mut self) -> usize {
+        let u = self.rng.next_f64().max(f64::MIN_POSITIVE);
+        (-u.ln() * self.ml).floor() as usize
+    }
+
+    fn top_layer(&self, index: usize) -> usize {
+        self.nodes[index].neighbors.len().saturating_sub(1)
+    }
+
+    /// query와 노드 벡터 간 거리(작을수록 가까움)를 반환한다.
+    fn distance(&self, query: &[f32], index: usize) -> f32 {
+        1.0 - linalg::dot(query, &self.nodes[index].vector)
+    }
+
+    /// 주어진 레이어에서 best-first로 `ef`개의 최근접 후보를 찾는다.
+    fn search_layer(
+        &self,
+        query: &[f32],
+        entry_points: &[usize],
+        ef: usize,
+        layer: usize,
+    ) -> Vec<(f32, usize)> {
+        let mut visited = HashSet::<usize>::new();
+        // 후보 min-heap(가까운 것 우선)과 결과 max-heap(먼 것이 top).
+        let mut candidates = BinaryHeap::<Reverse<(OrdF32, usize)>>::new();
+        let mut results = BinaryHeap::<(OrdF32, usize)>::new();
+
+        for &ep in entry_points {
+            if visited.insert(ep) {
+                let dist = self.distance(query, ep);
+                candidates.push(Reverse((OrdF32(dist), ep)));
+                results.push((OrdF32(dist), ep));
+            }
+        }
+
+        while let Some(Reverse((OrdF32(current_dist), current_idx))) = candidates.pop() {
+            let farthest = results.peek().map(|(OrdF32(d), _)| *d);
+            if let Some(farthest) = farthest {
+                if current_dist > farthest && results.len() >= ef {
+                    break;
+                }
+            }
+
+            for &neighbor in &self.nodes[current_idx].neighbors[layer] {
+                if visited.insert(neighbor) {
+                    let dist = self.distance(query, neighbor);
+                    let farthest = results.peek().map(|(OrdF32(d), _)| *d).unwrap_or(f32::INFINITY);
+                    if dist < farthest || results.len() < ef {
+                        candidates.push(Reverse((OrdF32(dist), neighbor)));
+                        results.push((OrdF32(dist), neighbor));
+                        if results.len() > ef {
+                            results.pop();
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut found = results
+            .into_iter()
+            .map(|(OrdF32(dist), idx)| (dist, idx))
+            .collect::<Vec<_>>();
+        found.sort_by(|left, right| left.0.total_cmp(&right.0).then_with(|| left.1.cmp(&right.1)));
+        found
+    }
+
+    /// 이웃 가지치기 휴리스틱으로 후보 중 최대 `m`개를 고른다.
+    ///
+    /// 후보가 query에 가깝더라도, 이미 선택된 이웃에 더 가까우면 버려 연결의
+    /// 다양성을 확보한다(HNSW 논문의 select-neighbors-heuristic).
+    fn select_neighbors(
+        &self,
+        query: &[f32],
+        candidates: &[(f32, usize)],
+        m: usize,
+    ) -> Vec<usize> {
+        let mut selected = Vec::<usize>::with_capacity(m);
+        for &(dist_to_query, idx) in candidates {
+            if selected.len() >= m {
+                break;
+            }
+            let keep = selected.iter().all(|&chosen| {
+                let dist_to_chosen = self.distance(&self.nodes[idx].vector, chosen);
+                dist_to_query < dist_to_chosen
+            });
+            if keep {
+                selected.push(idx);
+            }
+        }
+        selected
+    }
+
+    /// 이웃 수가 상한을 넘으면 거리순으로 정렬한 뒤 `select_neighbors` 휴리스틱으로
+    /// 최대 `m_max`개만 남긴다(가장 가까운 이웃이라도 다양성 기준에 걸리면 빠질 수 있다).
+    fn prune_neighbors(&mut self, index: usize, layer: usize, m_max: usize) {
+        if self.nodes[index].neighbors[layer].len() <= m_max {
+            return;
+        }
+
+        let base = self.nodes[index].vector.clone();
+        let mut ranked = self.nodes[index].neighbors[layer]
+            .iter()
+            .map(|&neighbor| (self.distance(&base, neighbor), neighbor))
+            .collect::<Vec<_>>();
+        ranked.sort_by(|left, right| left.0.total_cmp(&right.0).then_with(|| left.1.cmp(&right.1)));
+
+        let kept = self.select_neighbors(&base, &ranked, m_max);
+        self.nodes[index].neighbors[layer] = kept;
+    }
+}
+
+impl VdbAdapter for HnswIndex {
+    fn dim(&self) -> usize {
+        self.dim
+    }
+
+    fn search(&self, query: &[f32], limit: usize) -> Result<Vec<ScoredDoc>, String> {
+        if limit == 0 {
+            return Ok(Vec::new());
+        }
+        if query.len() != self.dim {
+            return Err(format!(
+                "HNSW 검색 쿼리 차원이 일치하지 않습니다: expected={}, actual={}",
+                self.dim,
+                query.len()
+            ));
+        }
+
+        let entry = match self.entry_point {
+            Some(entry) => entry,
+            None => return Ok(Vec::new()),
+        };
+
+        let top = self.top_layer(entry);
+        let mut cursor = entry;
+        let mut layer = top;
+        while layer > 0 {
+            let nearest = self.search_layer(query, &[cursor], 1, layer);
+            if let Some((_, idx)) = nearest.first() {
+                cursor = *idx;
+            }
+            layer -= 1;
+        }
+
+        let beam = self.ef.max(limit);
+        let found = self.search_layer(query, &[cursor], beam, 0);
+
+        let hits = found
+            .into_iter()
+            .take(limit)
+            .map(|(_, idx)| {
+                let node = &self.nodes[idx];
+                (node.id, linalg::dot(query, &node.vector))
+            })
+            .collect();
+        Ok(hits)
+    }
+
+    fn fetch_vectors(&self, doc_ids: &[DocId]) -> Result<Vec<DocVector>, String> {
+        let mut vectors = Vec::with_capacity(doc_ids.len());
+        for doc_id in doc_ids {
+            let index = self.id_to_index.get(doc_id).ok_or_else(|| {
+                format!("doc_id={} 벡터를 HNSW 인덱스에서 찾을 수 없습니다", doc_id)
+            })?;
+            vectors.push(DocVector {
+                id: *doc_id,
+                vector: self.nodes[*index].vector.clone(),
+            });
+        }
+        Ok(vectors)
+    }
+}
@@ -6,7 +6,10 @@ pub struct SegmentRange {
 
 pub const SEGMENT_COUNT: usize = 4;
 pub const SEGMENT_TOP_K: usize = 100;
+/// 생존 후보 개수의 기본값이다. 파이프라인 호출 시 덮어쓸 수 있다.
 pub const SURVIVOR_COUNT: usize = 5;
+/// Reciprocal Rank Fusion의 표준 상수(k)의 기본값이다.
+pub const RRF_K: f32 = 60.0;
 pub const PRF_ALPHA: f32 = 0.7;
 pub const MAX_REFINEMENT_ROUNDS: usize = 8;
 
@@ -0,0 +1,131 @@
+// 목적:
+// - Python에서 재사용 가능한 영속 검색/적재 엔진 핸들을 노출한다.
+//
+// 설명:
+// - 풀과 필터 클라이언트를 객체 수명 동안 유지해 요청마다 재구성하지 않는다.
+// - search/ingest는 JSON 페이로드를 받아 결과 JSON을 반환하고, close는 풀을 비운다.
+//
+// 디자인 패턴:
+// - 파사드(Facade) + 자원 핸들(Resource Handle).
+//
+// 참조:
+// - src_rs/core/engine.rs
+
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+use serde::Deserialize;
+use tokio::runtime::{Builder, Runtime};
+
+use crate::api::errors::to_py_err;
+use crate::core::engine::SearchEngine;
+use crate::core::filter_http::FilterHttpConfigPayload;
+use crate::core::ingestion_pipeline::{EnqueueIngestionRequestPayload, IngestionRequestPayload};
+use crate::core::search_pipeline::{PostgresConfigPayload, SearchRequestPayload};
+
+#[derive(Debug, Clone, Deserialize)]
+struct EngineConfigPayload {
+    postgres: PostgresConfigPayload,
+    filter_http: FilterHttpConfigPayload,
+    /// 내구성 적재 큐 테이블 이름. 없으면 엔진 기본값을 쓴다.
+    #[serde(default)]
+    ingestion_queue_table: Option<String>,
+}
+
+/// Python에 노출되는 영속 엔진 핸들이다.
+#[pyclass(name = "SearchEngine")]
+pub struct PySearchEngine {
+    runtime: Runtime,
+    engine: SearchEngine,
+}
+
+#[pymethods]
+impl PySearchEngine {
+    /// 엔진 설정(JSON)으로 풀과 필터 클라이언트를 한 번 구성한다.
+    #[new]
+    pub fn new(config_json: &str) -> PyResult<Self> {
+        let config: EngineConfigPayload = serde_json::from_str(config_json).map_err(|error| {
+            PyRuntimeError::new_err(format!("엔진 설정 JSON 파싱에 실패했습니다: {}", error))
+        })?;
+
+        let runtime = create_runtime().map_err(PyRuntimeError::new_err)?;
+        let engine = runtime
+            .block_on(SearchEngine::connect(
+                &config.postgres,
+                config.filter_http,
+                config.ingestion_queue_table.as_deref(),
+            ))
+            .map_err(to_py_err)?;
+
+        Ok(Self { runtime, engine })
+    }
+
+    /// 기존 풀을 재사용해 검색 페이로드(JSON)를 실행한다.
+    pub fn search(&self, payload_json: &str) -> PyResult<String> {
+        let payload: SearchRequestPayload = serde_json::from_str(payload_json).map_err(|error| {
+            PyRuntimeError::new_err(format!("검색 페이로드 JSON 파싱에 실패했습니다: {}", error))
+        })?;
+
+        let result = self
+            .runtime
+            .block_on(self.engine.search(payload))
+            .map_err(to_py_err)?;
+
+        serde_json::to_string(&result)
+            .map_err(|error| PyRuntimeError::new_err(format!("검색 결과 직렬화 실패: {}", error)))
+    }
+
+    /// 기존 풀을 재사용해 적재 페이로드(JSON)를 실행한다.
+    pub fn ingest(&self, payload_json: &str) -> PyResult<String> {
+        let payload: IngestionRequestPayload =
+            serde_json::from_str(payload_json).map_err(|error| {
+                PyRuntimeError::new_err(format!("적재 페이로드 JSON 파싱에 실패했습니다: {}", error))
+            })?;
+
+        let result = self
+            .runtime
+            .block_on(self.engine.ingest(payload))
+            .map_err(to_py_err)?;
+
+        serde_json::to_string(&result)
+            .map_err(|error| PyRuntimeError::new_err(format!("적재 결과 직렬화 실패: {}", error)))
+    }
+
+    /// 적재 배치(JSON)를 내구성 큐에 넣고 작업 id들을 JSON으로 반환한다.
+    pub fn enqueue_ingestion(&self, payload_json: &str) -> PyResult<String> {
+        let payload: EnqueueIngestionRequestPayload =
+            serde_json::from_str(payload_json).map_err(|error| {
+                PyRuntimeError::new_err(format!(
+                    "큐 적재 페이로드 JSON 파싱에 실패했습니다: {}",
+                    error
+                ))
+            })?;
+
+        let result = self
+            .runtime
+            .block_on(self.engine.enqueue_ingestion(payload))
+            .map_err(to_py_err)?;
+
+        serde_json::to_string(&result)
+            .map_err(|error| PyRuntimeError::new_err(format!("큐 적재 결과 직렬화 실패: {}", error)))
+    }
+
+    /// `concurrency`개의 워커로 적재 큐를 소비한다. 정상 동작 중에는 반환하지 않으며,
+    /// 큐 테이블 DB 작업이 실패하면 예외를 던진다.
+    pub fn run_ingestion_worker(&self, concurrency: usize) -> PyResult<()> {
+        self.runtime
+            .block_on(self.engine.run_ingestion_worker(concurrency))
+            .map_err(to_py_err)
+    }
+
+    /// 풀을 닫아 연결을 정리한다.
+    pub fn close(&self) {
+        self.runtime.block_on(self.engine.close());
+    }
+}
+
+fn create_runtime() -> Result<Runtime, String> {
+    Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .map_err(|error| format!("Tokio 런타임 생성 실패: {}", error))
+}
@@ -0,0 +1,69 @@
+// 목적:
+// - Rust CoreError를 Python 예외로 변환한다.
+//
+// 설명:
+// - CoreError::Validation은 `code`/`field` 속성을 가진 별도의 ValidationError
+//   파이썬 예외로 변환해, 호출자가 메시지 문자열을 파싱하지 않고 분기할 수 있게 한다.
+// - 누적된 오류가 여러 개일 수 있으므로, 첫 오류의 code/field는 그대로 최상위
+//   속성으로 얹고(가장 흔한 단일 오류 케이스를 바로 처리할 수 있게), 전체 목록은
+//   `errors` 속성(code/field/message 튜플의 리스트)으로 노출한다.
+// - 그 외 변형은 기존처럼 PyRuntimeError로 떨어뜨린다.
+//
+// 디자인 패턴:
+// - 오류 변환(Error Adapter).
+//
+// 참조:
+// - src_rs/core/errors.rs
+// - src_rs/core/validation.rs
+
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyRuntimeError};
+use pyo3::prelude::*;
+
+use crate::core::errors::CoreError;
+use crate::core::validation::{format_validation_errors, ValidationError};
+
+create_exception!(_vtree_search, PyValidationError, PyException);
+
+/// CoreError를 Python 예외로 변환한다.
+pub fn to_py_err(error: CoreError) -> PyErr {
+    match error {
+        CoreError::Validation(errors) => validation_to_py_err(&errors),
+        other => PyRuntimeError::new_err(other.to_string()),
+    }
+}
+
+/// 검증 오류 목록을 PyValidationError로 변환한다.
+///
+/// 첫 오류의 field/code를 인스턴스 속성으로 얹어 `error.code`/`error.field`로
+/// 바로 접근할 수 있게 하고, 누적된 전체 오류는 `error.errors`(각 항목이
+/// `(code, field, message)` 튜플인 리스트)로 노출해 메시지 문자열 파싱 없이도
+/// 모든 위반 필드를 순회할 수 있게 한다.
+fn validation_to_py_err(errors: &[ValidationError]) -> PyErr {
+    let message = format_validation_errors(errors);
+    let py_err = PyValidationError::new_err(message);
+
+    let details = errors
+        .iter()
+        .map(|error| (validation_code_str(error.code), error.field, error.message.clone()))
+        .collect::<Vec<(String, &'static str, String)>>();
+
+    Python::with_gil(|py| {
+        let value = py_err.value(py);
+        if let Some((code, field, _)) = details.first() {
+            let _ = value.setattr("code", code);
+            let _ = value.setattr("field", field);
+        }
+        let _ = value.setattr("errors", details);
+    });
+
+    py_err
+}
+
+/// `ValidationCode`를 `error.code`/`error.errors`에 쓸 snake_case 문자열로 바꾼다.
+fn validation_code_str(code: crate::core::validation::ValidationCode) -> String {
+    serde_json::to_value(code)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
@@ -2,14 +2,16 @@
 // - Python FFI 경계 모듈을 선언한다.
 //
 // 설명:
-// - 검색/적재 브릿지를 분리해 Python 계층에서 두 클래스로 사용할 수 있게 한다.
+// - 1회성 검색 브릿지와 영속 엔진 핸들(검색+적재)을 분리해 노출한다.
 //
 // 디자인 패턴:
 // - 모듈 분리(Module Separation).
 //
 // 참조:
 // - src_rs/api/search_bridge.rs
-// - src_rs/api/ingestion_bridge.rs
+// - src_rs/api/engine_bridge.rs
+// - src_rs/api/errors.rs
 
-pub mod ingestion_bridge;
+pub mod engine_bridge;
+pub mod errors;
 pub mod search_bridge;
@@ -15,6 +15,7 @@ use pyo3::exceptions::PyRuntimeError;
 use pyo3::prelude::*;
 use tokio::runtime::{Builder, Runtime};
 
+use crate::api::errors::to_py_err;
 use crate::core::search_pipeline::{execute_search, SearchRequestPayload};
 
 /// Python에 노출되는 검색 브릿지 클래스다.
@@ -50,7 +51,7 @@ impl PySearchBridge {
         let runtime = create_runtime().map_err(PyRuntimeError::new_err)?;
         let result = runtime
             .block_on(execute_search(payload))
-            .map_err(|error| PyRuntimeError::new_err(error.to_string()))?;
+            .map_err(to_py_err)?;
 
         serde_json::to_string(&result)
             .map_err(|error| PyRuntimeError::new_err(format!("검색 결과 직렬화 실패: {}", error)))
@@ -0,0 +1,147 @@
+// 목적:
+// - 연결 풀을 재사용하는 영속 검색/적재 엔진 핸들을 제공한다.
+//
+// 설명:
+// - PostgresRepository와 FilterHttpClient를 한 번만 만들어 FFI 경계 너머로 유지한다.
+// - 매 요청마다 풀을 새로 만들지 않고 기존 풀을 빌려 핸드셰이크 비용을 제거한다.
+// - 적재는 동기 upsert(ingest) 외에 내구성 큐(enqueue_ingestion/run_ingestion_worker)로도
+//   처리할 수 있어, 쓰기 지연과 적재 처리량을 분리할 수 있다.
+//
+// 디자인 패턴:
+// - 핸들/리소스 소유(Handle/Resource Ownership).
+//
+// 참조:
+// - src_rs/core/search_pipeline.rs
+// - src_rs/core/ingestion_pipeline.rs
+// - src_rs/index/job_queue.rs
+
+use std::sync::Arc;
+
+use tokio::sync::OnceCell;
+
+use crate::core::errors::CoreResult;
+use crate::core::filter_http::{FilterHttpClient, FilterHttpConfigPayload};
+use crate::core::ingestion_pipeline::{
+    enqueue_ingestion, run_ingestion, EnqueueIngestionRequestPayload, EnqueueIngestionResultPayload,
+    IngestionRequestPayload, IngestionResultPayload,
+};
+use crate::core::search_pipeline::{
+    run_query, validate_payload, PostgresConfigPayload, SearchRequestPayload, SearchResultPayload,
+    SnippetOptions,
+};
+use crate::index::job_queue::IngestionJobQueue;
+use crate::index::postgres_repo::PostgresRepository;
+
+/// 내구성 적재 큐 테이블의 기본 이름이다.
+const DEFAULT_INGESTION_QUEUE_TABLE: &str = "ingestion_job_queue";
+
+/// 장수명 풀과 캐시된 필터 클라이언트를 소유하는 엔진 핸들이다.
+pub struct SearchEngine {
+    repository: Arc<PostgresRepository>,
+    filter_client: FilterHttpClient,
+    job_queue: Arc<IngestionJobQueue>,
+    /// 큐 테이블 DDL(`CREATE TABLE IF NOT EXISTS`)을 첫 적재 큐 호출까지 미룬다.
+    ///
+    /// `connect`에서 무조건 실행하면 검색 전용 호출자도 Postgres 역할에
+    /// `CREATE TABLE` 권한을 요구하게 되므로, 실제로 큐를 쓰는 첫 호출에서만
+    /// 준비한다.
+    queue_table_ready: OnceCell<()>,
+}
+
+impl SearchEngine {
+    /// 풀과 필터 클라이언트를 한 번 구성해 엔진을 연결한다.
+    ///
+    /// 내구성 적재 큐는 `queue_table`(없으면 기본 이름)로 핸들만 준비하고,
+    /// 큐 테이블 DDL은 `enqueue_ingestion`/`run_ingestion_worker`를 처음
+    /// 호출할 때까지 지연시킨다.
+    pub async fn connect(
+        postgres: &PostgresConfigPayload,
+        filter_http: FilterHttpConfigPayload,
+        queue_table: Option<&str>,
+    ) -> CoreResult<Self> {
+        let repository = Arc::new(
+            PostgresRepository::new(
+                &postgres.dsn,
+                &postgres.summary_table,
+                &postgres.page_table,
+                postgres.pool_min,
+                postgres.pool_max,
+                postgres.connect_timeout_ms,
+                postgres.statement_timeout_ms,
+            )
+            .await?,
+        );
+        let filter_client = FilterHttpClient::new(filter_http)?;
+
+        let job_queue = Arc::new(IngestionJobQueue::new(
+            repository.clone(),
+            queue_table.unwrap_or(DEFAULT_INGESTION_QUEUE_TABLE),
+            None,
+        )?);
+
+        Ok(Self {
+            repository,
+            filter_client,
+            job_queue,
+            queue_table_ready: OnceCell::new(),
+        })
+    }
+
+    /// 큐 테이블이 아직 준비되지 않았다면 지금 준비한다(최초 1회만 DDL 실행).
+    async fn ensure_queue_ready(&self) -> CoreResult<()> {
+        self.queue_table_ready
+            .get_or_try_init(|| self.job_queue.ensure_queue_table())
+            .await?;
+        Ok(())
+    }
+
+    /// 기존 풀/클라이언트를 빌려 검색 요청을 실행한다.
+    pub async fn search(&self, request: SearchRequestPayload) -> CoreResult<SearchResultPayload> {
+        validate_payload(&request)?;
+        let snippet_options = SnippetOptions::from_request(&request);
+
+        run_query(
+            &self.repository,
+            &self.filter_client,
+            &request.job_id,
+            &request.question,
+            &request.query_embedding,
+            request.top_k,
+            request.entry_limit,
+            request.page_limit,
+            request.worker_concurrency,
+            request.rrf_k,
+            &snippet_options,
+        )
+        .await
+    }
+
+    /// 기존 풀을 빌려 적재 요청을 실행한다.
+    pub async fn ingest(
+        &self,
+        request: IngestionRequestPayload,
+    ) -> CoreResult<IngestionResultPayload> {
+        run_ingestion(&self.repository, request).await
+    }
+
+    /// 적재 배치를 내구성 큐에 넣고 즉시 반환한다(동기 upsert 없이 쓰기 지연을 분리).
+    pub async fn enqueue_ingestion(
+        &self,
+        request: EnqueueIngestionRequestPayload,
+    ) -> CoreResult<EnqueueIngestionResultPayload> {
+        self.ensure_queue_ready().await?;
+        enqueue_ingestion(&self.job_queue, request).await
+    }
+
+    /// `concurrency`개의 워커로 적재 큐를 소비한다. 정상 동작 중에는 반환하지 않으며,
+    /// 큐 테이블 DB 작업이 실패하면 그 오류를 반환한다.
+    pub async fn run_ingestion_worker(&self, concurrency: usize) -> CoreResult<()> {
+        self.ensure_queue_ready().await?;
+        self.job_queue.clone().run_worker(concurrency).await
+    }
+
+    /// 풀을 닫아 연결을 정리한다.
+    pub async fn close(&self) {
+        self.repository.close().await;
+    }
+}
@@ -13,6 +13,8 @@
 
 use thiserror::Error;
 
+use crate::core::validation::{format_validation_errors, ValidationError};
+
 /// 코어 계층에서 공통으로 사용하는 오류 열거형이다.
 #[derive(Debug, Error)]
 pub enum CoreError {
@@ -28,6 +30,8 @@ pub enum CoreError {
     Serialization(String),
     #[error("런타임 처리 중 오류가 발생했습니다: {0}")]
     Runtime(String),
+    #[error("{}", format_validation_errors(.0))]
+    Validation(Vec<ValidationError>),
 }
 
 pub type CoreResult<T> = Result<T, CoreError>;
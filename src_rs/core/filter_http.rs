@@ -4,9 +4,11 @@
 // 설명:
 // - 질문/페이지 본문을 HTTP 엔드포인트에 전달하고 keep 여부를 판정한다.
 // - 병렬 처리 수는 semaphore로 제한한다.
+// - 일시적 오류는 지터드 지수 백오프로 재시도하고, 연속 실패가 임계치에 도달하면
+//   회로 차단기로 남은 후보를 keep=true 폴백으로 단락한다.
 //
 // 디자인 패턴:
-// - 어댑터(Adapter) + 제한 병렬 처리(Bounded Concurrency).
+// - 어댑터(Adapter) + 제한 병렬 처리(Bounded Concurrency) + 회로 차단기(Circuit Breaker).
 //
 // 참조:
 // - src_rs/core/search_pipeline.rs
@@ -14,11 +16,30 @@
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::Arc;
-use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::{mpsc, OwnedSemaphorePermit, Semaphore};
 use tokio::task::JoinSet;
 
 use crate::core::errors::{CoreError, CoreResult};
+use crate::core::telemetry;
+use metrics::{gauge, histogram};
+
+/// 회로 차단 시 후보에 적용하는 폴백 사유다.
+const BREAKER_FALLBACK_REASON: &str = "filter-unavailable";
+
+fn default_max_attempts() -> u32 {
+    3
+}
+
+fn default_base_delay_ms() -> u64 {
+    100
+}
+
+fn default_breaker_threshold() -> u32 {
+    5
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FilterHttpConfigPayload {
@@ -26,6 +47,15 @@ pub struct FilterHttpConfigPayload {
     pub timeout_ms: u64,
     pub auth_token: Option<String>,
     pub model: Option<String>,
+    /// 재시도 포함 최대 시도 횟수(1이면 재시도 없음).
+    #[serde(default = "default_max_attempts")]
+    pub max_attempts: u32,
+    /// 지수 백오프의 기준 지연(밀리초).
+    #[serde(default = "default_base_delay_ms")]
+    pub base_delay_ms: u64,
+    /// 회로 차단기를 여는 연속 실패 임계치.
+    #[serde(default = "default_breaker_threshold")]
+    pub breaker_threshold: u32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,6 +71,51 @@ pub struct FilterCandidateInput {
     pub content: String,
 }
 
+/// 배치 범위에서 공유되는 경량 회로 차단기다.
+struct CircuitBreaker {
+    consecutive_failures: AtomicU32,
+    tripped: AtomicBool,
+    threshold: u32,
+}
+
+impl CircuitBreaker {
+    fn new(threshold: u32) -> Self {
+        Self {
+            consecutive_failures: AtomicU32::new(0),
+            tripped: AtomicBool::new(false),
+            threshold: threshold.max(1),
+        }
+    }
+
+    fn is_open(&self) -> bool {
+        self.tripped.load(Ordering::Relaxed)
+    }
+
+    fn record_success(&self) {
+        self.consecutive_failures.store(0, Ordering::Relaxed);
+    }
+
+    fn record_failure(&self) {
+        let failures = self.consecutive_failures.fetch_add(1, Ordering::Relaxed) + 1;
+        if failures >= self.threshold {
+            self.tripped.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
+/// 단일 시도의 결과 구분이다.
+enum AttemptOutcome {
+    /// 판정 성공.
+    Decided(FilterDecision),
+    /// 재시도 가능한 일시적 실패(선택적 Retry-After 포함).
+    Retriable {
+        detail: String,
+        retry_after: Option<Duration>,
+    },
+    /// 재시도 불가한 오류(설정/응답 문제).
+    Fatal(CoreError),
+}
+
 #[derive(Clone)]
 pub struct FilterHttpClient {
     client: Client,
@@ -83,20 +158,25 @@ impl FilterHttpClient {
 
         let bounded = concurrency.max(1);
         let semaphore = Arc::new(Semaphore::new(bounded));
+        let breaker = Arc::new(CircuitBreaker::new(self.config.breaker_threshold));
         let mut join_set = JoinSet::new();
 
         for candidate in candidates {
             let permit = semaphore.clone().acquire_owned().await.map_err(|error| {
                 CoreError::Runtime(format!("필터 semaphore 획득 실패: {}", error))
             })?;
+            gauge!(telemetry::FILTER_INFLIGHT_PERMITS)
+                .set((bounded - semaphore.available_permits()) as f64);
+
             let cloned_client = self.clone();
             let cloned_question = question.to_string();
             let cloned_candidate = candidate.clone();
+            let cloned_breaker = breaker.clone();
 
             join_set.spawn(async move {
                 let _permit: OwnedSemaphorePermit = permit;
                 cloned_client
-                    .filter_single(&cloned_question, &cloned_candidate)
+                    .filter_single(&cloned_question, &cloned_candidate, &cloned_breaker)
                     .await
             });
         }
@@ -113,11 +193,121 @@ impl FilterHttpClient {
         Ok(decisions)
     }
 
+    /// `filter_candidates`와 동일하게 동시성을 제한해 판정하되, 전체 완료를
+    /// 기다리지 않고 각 판정이 끝나는 대로 채널로 흘려보낸다.
+    ///
+    /// 호출자는 반환된 수신자를 소비하면서 가장 느린 작업이 끝나기 전에
+    /// 이미 도착한 판정부터 처리를 시작할 수 있다.
+    pub async fn filter_candidates_stream(
+        &self,
+        question: &str,
+        candidates: &[FilterCandidateInput],
+        concurrency: usize,
+    ) -> CoreResult<mpsc::Receiver<CoreResult<FilterDecision>>> {
+        if question.trim().is_empty() {
+            return Err(CoreError::InvalidInput(
+                "question은 비어 있을 수 없습니다".to_string(),
+            ));
+        }
+
+        let bounded = concurrency.max(1);
+        let semaphore = Arc::new(Semaphore::new(bounded));
+        let breaker = Arc::new(CircuitBreaker::new(self.config.breaker_threshold));
+        let (tx, rx) = mpsc::channel::<CoreResult<FilterDecision>>(bounded);
+
+        let client = self.clone();
+        let question = question.to_string();
+        let candidates = candidates.to_vec();
+
+        tokio::spawn(async move {
+            let mut join_set = JoinSet::new();
+            for candidate in candidates {
+                let permit = match semaphore.clone().acquire_owned().await {
+                    Ok(permit) => permit,
+                    Err(_) => break,
+                };
+                gauge!(telemetry::FILTER_INFLIGHT_PERMITS)
+                    .set((bounded - semaphore.available_permits()) as f64);
+
+                let cloned_client = client.clone();
+                let cloned_question = question.clone();
+                let cloned_breaker = breaker.clone();
+
+                join_set.spawn(async move {
+                    let _permit: OwnedSemaphorePermit = permit;
+                    cloned_client
+                        .filter_single(&cloned_question, &candidate, &cloned_breaker)
+                        .await
+                });
+            }
+
+            // 작업이 끝나는 순서대로(도착 순) 판정을 내보낸다 — 입력 순서를 보존하지 않는다.
+            while let Some(joined) = join_set.join_next().await {
+                let outcome = joined.map_err(|error| {
+                    CoreError::Runtime(format!("필터 작업 조인 실패: {}", error))
+                });
+                let result = match outcome {
+                    Ok(inner) => inner,
+                    Err(error) => Err(error),
+                };
+                let is_fatal = result.is_err();
+                if tx.send(result).await.is_err() || is_fatal {
+                    break;
+                }
+            }
+        });
+
+        Ok(rx)
+    }
+
     async fn filter_single(
         &self,
         question: &str,
         candidate: &FilterCandidateInput,
+        breaker: &CircuitBreaker,
     ) -> CoreResult<FilterDecision> {
+        // 차단기가 열려 있으면 엔드포인트를 더 두드리지 않고 폴백한다.
+        if breaker.is_open() {
+            return Ok(fallback_decision(&candidate.node_id));
+        }
+
+        let max_attempts = self.config.max_attempts.max(1);
+        let mut last_detail = String::new();
+
+        for attempt in 1..=max_attempts {
+            match self.send_once(question, candidate).await {
+                AttemptOutcome::Decided(decision) => {
+                    breaker.record_success();
+                    return Ok(decision);
+                }
+                AttemptOutcome::Fatal(error) => return Err(error),
+                AttemptOutcome::Retriable { detail, retry_after } => {
+                    last_detail = detail;
+                    if attempt < max_attempts {
+                        let delay = retry_after
+                            .unwrap_or_else(|| self.backoff_delay(attempt));
+                        tokio::time::sleep(delay).await;
+                        // 백오프 중 다른 작업이 차단기를 열었다면 즉시 폴백한다.
+                        if breaker.is_open() {
+                            return Ok(fallback_decision(&candidate.node_id));
+                        }
+                    }
+                }
+            }
+        }
+
+        // 재시도를 모두 소진했다: 실패를 기록하고 폴백 판정을 돌려준다.
+        breaker.record_failure();
+        let _ = last_detail;
+        Ok(fallback_decision(&candidate.node_id))
+    }
+
+    /// 한 번의 HTTP 왕복을 수행하고 결과 구분을 반환한다.
+    async fn send_once(
+        &self,
+        question: &str,
+        candidate: &FilterCandidateInput,
+    ) -> AttemptOutcome {
         #[derive(Serialize)]
         struct FilterRequest<'a> {
             question: &'a str,
@@ -137,39 +327,100 @@ impl FilterHttpClient {
             request_builder = request_builder.bearer_auth(token);
         }
 
-        let response = request_builder
-            .send()
-            .await
-            .map_err(|error| CoreError::Http(format!("필터 HTTP 요청 실패: {}", error)))?;
+        let started = std::time::Instant::now();
+        let response = match request_builder.send().await {
+            Ok(response) => response,
+            Err(error) => {
+                // 연결 오류/타임아웃은 일시적 실패로 본다.
+                return AttemptOutcome::Retriable {
+                    detail: format!("필터 HTTP 요청 실패: {}", error),
+                    retry_after: None,
+                };
+            }
+        };
+        histogram!(telemetry::FILTER_ROUNDTRIP_LATENCY).record(started.elapsed().as_secs_f64());
 
         let status = response.status();
-        let body = response
-            .text()
-            .await
-            .map_err(|error| CoreError::Http(format!("필터 HTTP 본문 읽기 실패: {}", error)))?;
+        if status.is_server_error() || status.as_u16() == 429 {
+            let retry_after = parse_retry_after(&response);
+            return AttemptOutcome::Retriable {
+                detail: format!("필터 HTTP 상태 오류: status={}", status),
+                retry_after,
+            };
+        }
+
+        let body = match response.text().await {
+            Ok(body) => body,
+            Err(error) => {
+                return AttemptOutcome::Retriable {
+                    detail: format!("필터 HTTP 본문 읽기 실패: {}", error),
+                    retry_after: None,
+                };
+            }
+        };
 
         if !status.is_success() {
-            return Err(CoreError::Http(format!(
+            return AttemptOutcome::Fatal(CoreError::Http(format!(
                 "필터 HTTP 상태 오류: status={}, body={}",
                 status, body
             )));
         }
 
-        let (keep, reason) = parse_filter_response(&body).map_err(|error| {
-            CoreError::Serialization(format!(
+        match parse_filter_response(&body) {
+            Ok((keep, reason)) => AttemptOutcome::Decided(FilterDecision {
+                node_id: candidate.node_id.clone(),
+                keep,
+                reason,
+            }),
+            Err(error) => AttemptOutcome::Fatal(CoreError::Serialization(format!(
                 "필터 응답 파싱 실패: {}, body={}",
                 error, body
-            ))
-        })?;
+            ))),
+        }
+    }
 
-        Ok(FilterDecision {
-            node_id: candidate.node_id.clone(),
-            keep,
-            reason,
-        })
+    /// `base * 2^(attempt-1)`에 풀 지터를 적용한 백오프 지연을 계산한다.
+    fn backoff_delay(&self, attempt: u32) -> Duration {
+        let base = self.config.base_delay_ms.max(1);
+        let exponent = attempt.saturating_sub(1).min(16);
+        let ceiling = base.saturating_mul(1u64 << exponent);
+        let jittered = jitter_millis(ceiling);
+        Duration::from_millis(jittered)
+    }
+}
+
+/// 회로 차단/재시도 소진 시 사용할 보수적 폴백 판정이다.
+fn fallback_decision(node_id: &str) -> FilterDecision {
+    FilterDecision {
+        node_id: node_id.to_string(),
+        keep: true,
+        reason: BREAKER_FALLBACK_REASON.to_string(),
+    }
+}
+
+/// Retry-After 헤더(초 단위 정수)를 파싱한다. HTTP-date 형식은 무시한다.
+fn parse_retry_after(response: &reqwest::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|raw| raw.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+/// `[0, ceiling]` 범위의 지터를 시스템 시계 나노초에서 유도해 반환한다.
+fn jitter_millis(ceiling: u64) -> u64 {
+    if ceiling == 0 {
+        return 0;
     }
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.subsec_nanos() as u64)
+        .unwrap_or(0);
+    nanos % (ceiling + 1)
 }
 
+
 fn parse_filter_response(body: &str) -> Result<(bool, String), String> {
     let trimmed = body.trim();
 
@@ -203,3 +454,78 @@ fn parse_filter_response(body: &str) -> Result<(bool, String), String> {
 
     Err("지원하지 않는 필터 응답 형식".to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn client_with(base_delay_ms: u64, breaker_threshold: u32) -> FilterHttpClient {
+        FilterHttpClient::new(FilterHttpConfigPayload {
+            url: "http://localhost/filter".to_string(),
+            timeout_ms: 1000,
+            auth_token: None,
+            model: None,
+            max_attempts: 3,
+            base_delay_ms,
+            breaker_threshold,
+        })
+        .expect("유효한 설정이어야 합니다")
+    }
+
+    #[test]
+    fn backoff_delay_doubles_ceiling_per_attempt_and_stays_within_it() {
+        let client = client_with(100, 5);
+
+        for attempt in 1..=5u32 {
+            let ceiling = 100u64.saturating_mul(1u64 << attempt.saturating_sub(1).min(16));
+            let delay = client.backoff_delay(attempt);
+            assert!(delay.as_millis() as u64 <= ceiling);
+        }
+    }
+
+    #[test]
+    fn circuit_breaker_trips_after_threshold_consecutive_failures() {
+        let breaker = CircuitBreaker::new(3);
+
+        breaker.record_failure();
+        breaker.record_failure();
+        assert!(!breaker.is_open());
+
+        breaker.record_failure();
+        assert!(breaker.is_open());
+    }
+
+    #[test]
+    fn circuit_breaker_success_resets_failure_streak() {
+        let breaker = CircuitBreaker::new(3);
+
+        breaker.record_failure();
+        breaker.record_failure();
+        breaker.record_success();
+        breaker.record_failure();
+        breaker.record_failure();
+
+        assert!(!breaker.is_open());
+    }
+
+    #[test]
+    fn parse_filter_response_accepts_binary_and_json_forms() {
+        assert_eq!(
+            parse_filter_response("1").unwrap(),
+            (true, "binary-response".to_string())
+        );
+        assert_eq!(
+            parse_filter_response("0").unwrap(),
+            (false, "binary-response".to_string())
+        );
+        assert_eq!(
+            parse_filter_response(r#"{"keep": true, "reason": "relevant"}"#).unwrap(),
+            (true, "relevant".to_string())
+        );
+        assert_eq!(
+            parse_filter_response(r#"{"result": "0"}"#).unwrap(),
+            (false, "json-result".to_string())
+        );
+        assert!(parse_filter_response(r#"{"unexpected": true}"#).is_err());
+    }
+}
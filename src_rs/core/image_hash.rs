@@ -0,0 +1,159 @@
+// 목적:
+// - 페이지 이미지의 지각 해시(perceptual hash)를 계산한다.
+//
+// 설명:
+// - 32x32 그레이스케일로 축소한 뒤 2-D DCT의 저주파 8x8 블록으로 64비트 해시를 만든다.
+// - 해밍 거리로 시각적 근접 중복을 판정할 수 있게 한다.
+//
+// 디자인 패턴:
+// - 순수 함수(Pure Function) + 어댑터(Adapter, HTTP 다운로드).
+//
+// 참조:
+// - src_rs/index/postgres_repo.rs
+
+use crate::core::errors::{CoreError, CoreResult};
+
+const RESIZE_DIM: u32 = 32;
+const DCT_BLOCK: usize = 8;
+
+/// URL에서 이미지를 받아 64비트 DCT 지각 해시를 계산한다.
+pub async fn phash_from_url(client: &reqwest::Client, url: &str) -> CoreResult<i64> {
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|error| CoreError::Http(format!("이미지 다운로드 실패: {}", error)))?;
+    let bytes = response
+        .bytes()
+        .await
+        .map_err(|error| CoreError::Http(format!("이미지 본문 읽기 실패: {}", error)))?;
+
+    phash_from_bytes(&bytes)
+}
+
+/// 인메모리 이미지 바이트에서 64비트 DCT 지각 해시를 계산한다.
+pub fn phash_from_bytes(bytes: &[u8]) -> CoreResult<i64> {
+    let image = image::load_from_memory(bytes)
+        .map_err(|error| CoreError::Runtime(format!("이미지 디코딩 실패: {}", error)))?;
+
+    // 32x32 그레이스케일로 축소한다.
+    let resized = image
+        .resize_exact(RESIZE_DIM, RESIZE_DIM, image::imageops::FilterType::Triangle)
+        .to_luma8();
+
+    let dim = RESIZE_DIM as usize;
+    let mut pixels = vec![0.0f64; dim * dim];
+    for (index, pixel) in resized.pixels().enumerate() {
+        pixels[index] = pixel[0] as f64;
+    }
+
+    let coefficients = dct_2d_low_frequency(&pixels, dim);
+
+    // DC 항(좌상단)을 제외한 63개 계수의 중앙값을 기준으로 비트를 세운다.
+    let mut rest = coefficients
+        .iter()
+        .enumerate()
+        .filter_map(|(index, value)| if index == 0 { None } else { Some(*value) })
+        .collect::<Vec<_>>();
+    let median = median_of(&mut rest);
+
+    let mut hash = 0u64;
+    for (index, value) in coefficients.iter().enumerate() {
+        if index == 0 {
+            continue;
+        }
+        if *value > median {
+            hash |= 1u64 << index;
+        }
+    }
+
+    Ok(hash as i64)
+}
+
+/// 두 지각 해시 사이의 해밍 거리를 반환한다.
+pub fn hamming_distance(left: i64, right: i64) -> u32 {
+    (left ^ right).count_ones()
+}
+
+/// 2-D DCT-II의 좌상단 8x8 저주파 계수만 직접 계산한다.
+fn dct_2d_low_frequency(pixels: &[f64], dim: usize) -> Vec<f64> {
+    let mut coefficients = vec![0.0f64; DCT_BLOCK * DCT_BLOCK];
+    let scale = std::f64::consts::PI / dim as f64;
+
+    for u in 0..DCT_BLOCK {
+        for v in 0..DCT_BLOCK {
+            let mut sum = 0.0;
+            for x in 0..dim {
+                let cos_x = ((x as f64 + 0.5) * u as f64 * scale).cos();
+                for y in 0..dim {
+                    let cos_y = ((y as f64 + 0.5) * v as f64 * scale).cos();
+                    sum += pixels[x * dim + y] * cos_x * cos_y;
+                }
+            }
+            coefficients[u * DCT_BLOCK + v] = sum;
+        }
+    }
+
+    coefficients
+}
+
+/// 슬라이스의 중앙값을 계산한다(입력은 정렬된다).
+fn median_of(values: &mut [f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.sort_by(|left, right| left.total_cmp(right));
+    let mid = values.len() / 2;
+    if values.len() % 2 == 0 {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{ImageBuffer, Luma};
+    use std::io::Cursor;
+
+    fn encode_png(pixel: impl Fn(u32, u32) -> u8) -> Vec<u8> {
+        let buffer: ImageBuffer<Luma<u8>, Vec<u8>> =
+            ImageBuffer::from_fn(RESIZE_DIM, RESIZE_DIM, |x, y| Luma([pixel(x, y)]));
+
+        let mut bytes = Vec::new();
+        image::DynamicImage::ImageLuma8(buffer)
+            .write_to(&mut Cursor::new(&mut bytes), image::ImageFormat::Png)
+            .expect("테스트 이미지 PNG 인코딩은 항상 성공해야 한다");
+        bytes
+    }
+
+    #[test]
+    fn phash_from_bytes_is_stable_for_identical_images() {
+        let bytes = encode_png(|x, y| if (x + y) % 2 == 0 { 40 } else { 210 });
+
+        let first = phash_from_bytes(&bytes).expect("유효한 PNG는 디코딩되어야 한다");
+        let second = phash_from_bytes(&bytes).expect("유효한 PNG는 디코딩되어야 한다");
+
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn phash_from_bytes_differs_for_visually_distinct_images() {
+        let checkerboard = encode_png(|x, y| if (x + y) % 2 == 0 { 10 } else { 245 });
+        let gradient = encode_png(|x, _| (x * 8) as u8);
+
+        let checkerboard_hash =
+            phash_from_bytes(&checkerboard).expect("유효한 PNG는 디코딩되어야 한다");
+        let gradient_hash = phash_from_bytes(&gradient).expect("유효한 PNG는 디코딩되어야 한다");
+
+        assert!(hamming_distance(checkerboard_hash, gradient_hash) > 0);
+    }
+
+    #[test]
+    fn hamming_distance_counts_differing_bits() {
+        assert_eq!(hamming_distance(0b1010, 0b1010), 0);
+        assert_eq!(hamming_distance(0b0000, 0b1111), 4);
+        assert_eq!(hamming_distance(i64::MIN, 0), 1);
+    }
+}
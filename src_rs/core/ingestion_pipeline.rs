@@ -14,7 +14,10 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use crate::core::errors::{CoreError, CoreResult};
+use crate::core::image_hash;
+use crate::core::validation::{ValidationCode, ValidationError};
 use crate::core::search_pipeline::PostgresConfigPayload;
+use crate::index::job_queue::{IngestionBatch, IngestionJobQueue};
 use crate::index::postgres_repo::{IngestionPageNodeRecord, IngestionSummaryNodeRecord, PostgresRepository};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -55,14 +58,24 @@ pub struct IngestionResultPayload {
     pub touched_summary_nodes: u64,
 }
 
+/// 내구성 큐에 넣을 적재 배치 요청이다. 동기 upsert와 달리 DB 쓰기를 기다리지 않는다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnqueueIngestionRequestPayload {
+    #[serde(default)]
+    pub summary_nodes: Vec<IngestionSummaryNodePayload>,
+    #[serde(default)]
+    pub page_nodes: Vec<IngestionPageNodePayload>,
+}
+
+/// 큐에 적재된 작업의 id들이다. 배치가 비어 있으면 해당 id는 비운다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnqueueIngestionResultPayload {
+    pub summary_job_id: Option<i64>,
+    pub page_job_id: Option<i64>,
+}
+
 /// 적재 파이프라인을 실행한다.
 pub async fn execute_ingestion(payload: IngestionRequestPayload) -> CoreResult<IngestionResultPayload> {
-    if payload.operation.trim().is_empty() {
-        return Err(CoreError::InvalidInput(
-            "operation은 비어 있을 수 없습니다".to_string(),
-        ));
-    }
-
     let repository = PostgresRepository::new(
         &payload.postgres.dsn,
         &payload.postgres.summary_table,
@@ -74,6 +87,22 @@ pub async fn execute_ingestion(payload: IngestionRequestPayload) -> CoreResult<I
     )
     .await?;
 
+    run_ingestion(&repository, payload).await
+}
+
+/// 이미 연결된 저장소로 적재 작업을 실행한다(영속 엔진에서 풀을 재사용).
+pub(crate) async fn run_ingestion(
+    repository: &PostgresRepository,
+    payload: IngestionRequestPayload,
+) -> CoreResult<IngestionResultPayload> {
+    if payload.operation.trim().is_empty() {
+        return Err(CoreError::Validation(vec![ValidationError::new(
+            "operation",
+            ValidationCode::Empty,
+            "operation은 비어 있을 수 없습니다",
+        )]));
+    }
+
     match payload.operation.as_str() {
         "upsert_document" => {
             let summary_records = payload
@@ -81,11 +110,12 @@ pub async fn execute_ingestion(payload: IngestionRequestPayload) -> CoreResult<I
                 .iter()
                 .map(map_summary_record)
                 .collect::<Vec<_>>();
-            let page_records = payload
+            let mut page_records = payload
                 .page_nodes
                 .iter()
                 .map(map_page_record)
                 .collect::<Vec<_>>();
+            enrich_image_phashes(&mut page_records).await;
 
             let upserted_summary = repository.upsert_summary_nodes(&summary_records).await?;
             let upserted_pages = repository.upsert_page_nodes(&page_records).await?;
@@ -98,11 +128,12 @@ pub async fn execute_ingestion(payload: IngestionRequestPayload) -> CoreResult<I
             })
         }
         "upsert_pages" => {
-            let page_records = payload
+            let mut page_records = payload
                 .page_nodes
                 .iter()
                 .map(map_page_record)
                 .collect::<Vec<_>>();
+            enrich_image_phashes(&mut page_records).await;
             let upserted_pages = repository.upsert_page_nodes(&page_records).await?;
 
             Ok(IngestionResultPayload {
@@ -114,7 +145,11 @@ pub async fn execute_ingestion(payload: IngestionRequestPayload) -> CoreResult<I
         }
         "rebuild_summary_embeddings" => {
             let document_id = payload.document_id.clone().ok_or_else(|| {
-                CoreError::InvalidInput("document_id가 필요합니다".to_string())
+                CoreError::Validation(vec![ValidationError::new(
+                    "document_id",
+                    ValidationCode::Missing,
+                    "document_id가 필요합니다",
+                )])
             })?;
             let touched = repository.touch_summary_nodes(&document_id).await?;
 
@@ -125,13 +160,55 @@ pub async fn execute_ingestion(payload: IngestionRequestPayload) -> CoreResult<I
                 touched_summary_nodes: touched,
             })
         }
-        _ => Err(CoreError::InvalidInput(format!(
-            "지원하지 않는 operation입니다: {}",
-            payload.operation
-        ))),
+        _ => Err(CoreError::Validation(vec![ValidationError::new(
+            "operation",
+            ValidationCode::UnknownOperation,
+            format!("지원하지 않는 operation입니다: {}", payload.operation),
+        )])),
     }
 }
 
+/// 적재 배치를 큐에 넣어 동기 upsert를 기다리지 않고 즉시 반환한다(영속 엔진에서 큐를 재사용).
+pub(crate) async fn enqueue_ingestion(
+    queue: &IngestionJobQueue,
+    payload: EnqueueIngestionRequestPayload,
+) -> CoreResult<EnqueueIngestionResultPayload> {
+    let summary_job_id = if payload.summary_nodes.is_empty() {
+        None
+    } else {
+        let records = payload
+            .summary_nodes
+            .iter()
+            .map(map_summary_record)
+            .collect::<Vec<_>>();
+        Some(
+            queue
+                .enqueue_ingestion(&IngestionBatch::Summary(records))
+                .await?,
+        )
+    };
+
+    let page_job_id = if payload.page_nodes.is_empty() {
+        None
+    } else {
+        let records = payload
+            .page_nodes
+            .iter()
+            .map(map_page_record)
+            .collect::<Vec<_>>();
+        Some(
+            queue
+                .enqueue_ingestion(&IngestionBatch::Page(records))
+                .await?,
+        )
+    };
+
+    Ok(EnqueueIngestionResultPayload {
+        summary_job_id,
+        page_job_id,
+    })
+}
+
 fn map_summary_record(payload: &IngestionSummaryNodePayload) -> IngestionSummaryNodeRecord {
     IngestionSummaryNodeRecord {
         node_id: payload.node_id.clone(),
@@ -151,6 +228,21 @@ fn map_page_record(payload: &IngestionPageNodePayload) -> IngestionPageNodeRecor
         path: payload.path.clone(),
         content: payload.content.clone(),
         image_url: payload.image_url.clone(),
+        image_phash: None,
         metadata: payload.metadata.clone().unwrap_or(Value::Null),
     }
 }
+
+/// 이미지가 있는 페이지에 대해 지각 해시를 계산해 레코드에 채운다.
+///
+/// 이미지 다운로드/디코딩 실패는 텍스트 적재를 막지 않도록 무시하고 해시를 비워 둔다.
+async fn enrich_image_phashes(records: &mut [IngestionPageNodeRecord]) {
+    let client = reqwest::Client::new();
+    for record in records.iter_mut() {
+        if let Some(url) = record.image_url.clone() {
+            if let Ok(phash) = image_hash::phash_from_url(&client, &url).await {
+                record.image_phash = Some(phash);
+            }
+        }
+    }
+}
@@ -12,6 +12,10 @@
 // - src_rs/core/search_pipeline.rs
 // - src_rs/core/ingestion_pipeline.rs
 
+pub mod engine;
 pub mod errors;
+pub mod image_hash;
 pub mod ingestion_pipeline;
 pub mod search_pipeline;
+pub mod telemetry;
+pub mod validation;
@@ -13,14 +13,21 @@
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap};
+use std::sync::Arc;
 use std::time::Instant;
 
+use tokio::sync::{mpsc, Semaphore};
+use tokio::task::JoinSet;
+use tokio_stream::wrappers::ReceiverStream;
+
 use crate::core::errors::{CoreError, CoreResult};
 use crate::core::filter_http::{
     FilterCandidateInput, FilterDecision, FilterHttpClient, FilterHttpConfigPayload,
 };
-use crate::index::postgres_repo::{PageNodeRecord, PostgresRepository};
+use crate::core::validation::{ValidationCode, ValidationError};
+use crate::index::postgres_repo::{PageNodeRecord, PostgresRepository, SummaryNodeRecord};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PostgresConfigPayload {
@@ -42,11 +49,40 @@ pub struct SearchRequestPayload {
     pub entry_limit: usize,
     pub page_limit: usize,
     pub worker_concurrency: usize,
+    /// RRF 융합 상수 k(관례상 60).
+    #[serde(default = "default_rrf_k")]
+    pub rrf_k: f32,
+    /// 스니펫 문맥의 최대 토큰 수. 없으면 스니펫을 만들지 않는다.
+    #[serde(default)]
+    pub crop_length: Option<usize>,
+    /// 매칭 용어 앞에 붙일 태그.
+    #[serde(default = "default_highlight_pre_tag")]
+    pub highlight_pre_tag: String,
+    /// 매칭 용어 뒤에 붙일 태그.
+    #[serde(default = "default_highlight_post_tag")]
+    pub highlight_post_tag: String,
     pub postgres: PostgresConfigPayload,
     pub filter_http: FilterHttpConfigPayload,
     pub metadata: Option<Value>,
 }
 
+/// RRF 융합 상수 k의 관례적 기본값이다.
+const DEFAULT_RRF_K: f32 = 60.0;
+/// 잘린 스니펫 끝/앞에 붙일 표식이다.
+const CROP_MARKER: &str = "…";
+
+fn default_rrf_k() -> f32 {
+    DEFAULT_RRF_K
+}
+
+fn default_highlight_pre_tag() -> String {
+    "<em>".to_string()
+}
+
+fn default_highlight_post_tag() -> String {
+    "</em>".to_string()
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchCandidatePayload {
     pub node_id: String,
@@ -55,6 +91,8 @@ pub struct SearchCandidatePayload {
     pub content: String,
     pub image_url: Option<String>,
     pub reason: String,
+    /// 질의 용어 주변을 잘라 하이라이트한 미리보기. 설정된 경우에만 채워진다.
+    pub snippet: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -72,11 +110,43 @@ pub struct SearchResultPayload {
     pub metrics: SearchMetricsPayload,
 }
 
+/// 배치 검색의 개별 하위 질의다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubQueryPayload {
+    pub job_id: String,
+    pub question: String,
+    pub query_embedding: Vec<f32>,
+    pub top_k: usize,
+}
+
+/// 하나의 풀/클라이언트를 공유하는 배치 검색 요청이다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchSearchRequestPayload {
+    pub subqueries: Vec<SubQueryPayload>,
+    pub entry_limit: usize,
+    pub page_limit: usize,
+    pub worker_concurrency: usize,
+    /// RRF 융합 상수 k(관례상 60).
+    #[serde(default = "default_rrf_k")]
+    pub rrf_k: f32,
+    /// 스니펫 문맥의 최대 토큰 수. 없으면 스니펫을 만들지 않는다.
+    #[serde(default)]
+    pub crop_length: Option<usize>,
+    /// 매칭 용어 앞에 붙일 태그.
+    #[serde(default = "default_highlight_pre_tag")]
+    pub highlight_pre_tag: String,
+    /// 매칭 용어 뒤에 붙일 태그.
+    #[serde(default = "default_highlight_post_tag")]
+    pub highlight_post_tag: String,
+    pub postgres: PostgresConfigPayload,
+    pub filter_http: FilterHttpConfigPayload,
+    pub metadata: Option<Value>,
+}
+
 /// 검색 파이프라인을 실행한다.
 pub async fn execute_search(payload: SearchRequestPayload) -> CoreResult<SearchResultPayload> {
     validate_payload(&payload)?;
 
-    let started = Instant::now();
     let repository = PostgresRepository::new(
         &payload.postgres.dsn,
         &payload.postgres.summary_table,
@@ -88,21 +158,129 @@ pub async fn execute_search(payload: SearchRequestPayload) -> CoreResult<SearchR
     )
     .await?;
 
+    let filter_client = FilterHttpClient::new(payload.filter_http.clone())?;
+
+    run_query(
+        &repository,
+        &filter_client,
+        &payload.job_id,
+        &payload.question,
+        &payload.query_embedding,
+        payload.top_k,
+        payload.entry_limit,
+        payload.page_limit,
+        payload.worker_concurrency,
+        payload.rrf_k,
+        &SnippetOptions::from_request(&payload),
+    )
+    .await
+}
+
+/// 공유 풀/클라이언트로 여러 질의를 동시에 실행한다.
+///
+/// 저장소와 필터 클라이언트를 한 번만 만들어 버스트성 질의 사이에서
+/// 풀 설정과 HTTP 클라이언트 구성을 분할 상환한다.
+pub async fn execute_search_batch(
+    payload: BatchSearchRequestPayload,
+) -> CoreResult<Vec<SearchResultPayload>> {
+    validate_batch_payload(&payload)?;
+
+    let repository = Arc::new(
+        PostgresRepository::new(
+            &payload.postgres.dsn,
+            &payload.postgres.summary_table,
+            &payload.postgres.page_table,
+            payload.postgres.pool_min,
+            payload.postgres.pool_max,
+            payload.postgres.connect_timeout_ms,
+            payload.postgres.statement_timeout_ms,
+        )
+        .await?,
+    );
+    let filter_client = FilterHttpClient::new(payload.filter_http.clone())?;
+
+    let bounded = payload.worker_concurrency.max(1);
+    let semaphore = Arc::new(Semaphore::new(bounded));
+    let mut join_set = JoinSet::new();
+
+    for (index, sub) in payload.subqueries.iter().enumerate() {
+        let permit = semaphore.clone().acquire_owned().await.map_err(|error| {
+            CoreError::Runtime(format!("배치 semaphore 획득 실패: {}", error))
+        })?;
+        let repository = repository.clone();
+        let filter_client = filter_client.clone();
+        let sub = sub.clone();
+        let entry_limit = payload.entry_limit;
+        let page_limit = payload.page_limit;
+        let worker_concurrency = payload.worker_concurrency;
+        let rrf_k = payload.rrf_k;
+        let snippet_options = SnippetOptions {
+            crop_length: payload.crop_length,
+            highlight_pre_tag: payload.highlight_pre_tag.clone(),
+            highlight_post_tag: payload.highlight_post_tag.clone(),
+        };
+
+        join_set.spawn(async move {
+            let _permit = permit;
+            let result = run_query(
+                &repository,
+                &filter_client,
+                &sub.job_id,
+                &sub.question,
+                &sub.query_embedding,
+                sub.top_k,
+                entry_limit,
+                page_limit,
+                worker_concurrency,
+                rrf_k,
+                &snippet_options,
+            )
+            .await;
+            (index, result)
+        });
+    }
+
+    // 입력 순서를 보존하기 위해 index로 정렬 후 결과를 펼친다.
+    let mut ordered: Vec<(usize, SearchResultPayload)> = Vec::with_capacity(payload.subqueries.len());
+    while let Some(joined) = join_set.join_next().await {
+        let (index, result) = joined
+            .map_err(|error| CoreError::Runtime(format!("배치 작업 조인 실패: {}", error)))?;
+        ordered.push((index, result?));
+    }
+
+    ordered.sort_by_key(|(index, _)| *index);
+    Ok(ordered.into_iter().map(|(_, result)| result).collect())
+}
+
+/// 공유 저장소/필터 클라이언트로 단일 질의 파이프라인을 실행한다.
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn run_query(
+    repository: &PostgresRepository,
+    filter_client: &FilterHttpClient,
+    job_id: &str,
+    question: &str,
+    query_embedding: &[f32],
+    top_k: usize,
+    entry_limit: usize,
+    page_limit: usize,
+    worker_concurrency: usize,
+    rrf_k: f32,
+    snippet_options: &SnippetOptions,
+) -> CoreResult<SearchResultPayload> {
+    let started = Instant::now();
+
     let entry_records = repository
-        .search_summary_nodes(&payload.query_embedding, payload.entry_limit)
+        .search_summary_nodes(query_embedding, entry_limit)
         .await?;
 
-    let mut parent_score_map = HashMap::<String, f32>::new();
     let mut expanded_pages = Vec::<PageNodeRecord>::new();
     for entry in &entry_records {
-        parent_score_map.insert(entry.node_id.clone(), entry.score);
         let pages = repository
-            .fetch_pages_under_path(&entry.path, payload.page_limit)
+            .fetch_pages_under_path(&entry.path, query_embedding, page_limit)
             .await?;
         expanded_pages.extend(pages);
     }
 
-    let filter_client = FilterHttpClient::new(payload.filter_http.clone())?;
     let filter_inputs = expanded_pages
         .iter()
         .map(|page| FilterCandidateInput {
@@ -112,7 +290,7 @@ pub async fn execute_search(payload: SearchRequestPayload) -> CoreResult<SearchR
         .collect::<Vec<_>>();
 
     let filter_decisions = filter_client
-        .filter_candidates(&payload.question, &filter_inputs, payload.worker_concurrency)
+        .filter_candidates(question, &filter_inputs, worker_concurrency)
         .await?;
 
     let decision_map = filter_decisions
@@ -120,9 +298,14 @@ pub async fn execute_search(payload: SearchRequestPayload) -> CoreResult<SearchR
         .map(|decision| (decision.node_id.clone(), decision))
         .collect::<HashMap<_, _>>();
 
+    let fused_scores = fuse_scores(&entry_records, &expanded_pages, rrf_k);
+
     let mut kept = expanded_pages
         .into_iter()
-        .filter_map(|page| to_candidate(page, &parent_score_map, &decision_map))
+        .filter_map(|page| {
+            let decision = decision_map.get(&page.node_id)?;
+            to_candidate(page, decision, &fused_scores, question, snippet_options)
+        })
         .collect::<Vec<_>>();
 
     kept.sort_by(|left, right| {
@@ -133,8 +316,8 @@ pub async fn execute_search(payload: SearchRequestPayload) -> CoreResult<SearchR
             .then_with(|| left.path.cmp(&right.path))
     });
 
-    if kept.len() > payload.top_k {
-        kept.truncate(payload.top_k);
+    if kept.len() > top_k {
+        kept.truncate(top_k);
     }
 
     let elapsed = started.elapsed().as_millis();
@@ -146,27 +329,184 @@ pub async fn execute_search(payload: SearchRequestPayload) -> CoreResult<SearchR
     };
 
     Ok(SearchResultPayload {
-        job_id: payload.job_id,
+        job_id: job_id.to_string(),
         candidates: kept,
         metrics,
     })
 }
 
+/// 스트리밍 검색을 실행한다.
+///
+/// 필터 판정이 완료되는 대로(모든 HTTP 호출이 끝나길 기다리지 않고) 크기
+/// `top_k`의 경계 min-heap에 밀어 넣으므로, 큰 `page_limit * entry_limit`
+/// 팬아웃에서도 상위 `top_k`개 후보만 메모리에 남고, 가장 느린 필터 워커가
+/// 끝나기 전에 이미 도착한 판정부터 힙 갱신을 시작할 수 있다.
+/// 실제 조회/필터링은 백그라운드 태스크로 넘겨 호출자가 즉시 스트림 핸들을
+/// 받도록 한다. Python 쪽은 이를 비동기 이터레이터로 소비한다.
+pub async fn execute_search_stream(
+    payload: SearchRequestPayload,
+) -> CoreResult<ReceiverStream<CoreResult<SearchCandidatePayload>>> {
+    validate_payload(&payload)?;
+
+    let top_k = payload.top_k.max(1);
+    let (tx, rx) = mpsc::channel::<CoreResult<SearchCandidatePayload>>(top_k);
+
+    let result_tx = tx.clone();
+    tokio::spawn(async move {
+        if let Err(error) = stream_search(payload, top_k, &tx).await {
+            let _ = result_tx.send(Err(error)).await;
+        }
+    });
+
+    Ok(ReceiverStream::new(rx))
+}
+
+/// `execute_search_stream`의 백그라운드 본체다.
+///
+/// 저장소/필터 클라이언트 구성, pgvector 조회, 필터 HTTP 호출을 모두 이
+/// 태스크 안에서 수행하고, 필터 판정이 도착하는 족족 경계 힙에 반영한다.
+async fn stream_search(
+    payload: SearchRequestPayload,
+    top_k: usize,
+    tx: &mpsc::Sender<CoreResult<SearchCandidatePayload>>,
+) -> CoreResult<()> {
+    let repository = PostgresRepository::new(
+        &payload.postgres.dsn,
+        &payload.postgres.summary_table,
+        &payload.postgres.page_table,
+        payload.postgres.pool_min,
+        payload.postgres.pool_max,
+        payload.postgres.connect_timeout_ms,
+        payload.postgres.statement_timeout_ms,
+    )
+    .await?;
+    let filter_client = FilterHttpClient::new(payload.filter_http.clone())?;
+
+    let entry_records = repository
+        .search_summary_nodes(&payload.query_embedding, payload.entry_limit)
+        .await?;
+
+    let mut expanded_pages = Vec::<PageNodeRecord>::new();
+    for entry in &entry_records {
+        let pages = repository
+            .fetch_pages_under_path(&entry.path, &payload.query_embedding, payload.page_limit)
+            .await?;
+        expanded_pages.extend(pages);
+    }
+
+    let fused_scores = fuse_scores(&entry_records, &expanded_pages, payload.rrf_k);
+
+    let filter_inputs = expanded_pages
+        .iter()
+        .map(|page| FilterCandidateInput {
+            node_id: page.node_id.clone(),
+            content: page.content.clone(),
+        })
+        .collect::<Vec<_>>();
+
+    let pages_by_id = expanded_pages
+        .into_iter()
+        .map(|page| (page.node_id.clone(), page))
+        .collect::<HashMap<_, _>>();
+
+    let snippet_options = SnippetOptions::from_request(&payload);
+
+    let mut decision_rx = filter_client
+        .filter_candidates_stream(&payload.question, &filter_inputs, payload.worker_concurrency)
+        .await?;
+
+    // 판정이 도착하는 대로 경계 min-heap에 밀어 넣어 상위 top_k만 유지한다.
+    let mut heap = BinaryHeap::<Reverse<RankedCandidate>>::with_capacity(top_k + 1);
+    while let Some(decision) = decision_rx.recv().await {
+        let decision = decision?;
+        let Some(page) = pages_by_id.get(&decision.node_id) else {
+            continue;
+        };
+        if let Some(candidate) = to_candidate(
+            page.clone(),
+            &decision,
+            &fused_scores,
+            &payload.question,
+            &snippet_options,
+        ) {
+            heap.push(Reverse(RankedCandidate::new(candidate)));
+            if heap.len() > top_k {
+                heap.pop();
+            }
+        }
+    }
+
+    // Reverse 힙의 정렬 결과는 점수 내림차순(동점 시 path 오름차순)이다.
+    for ranked in heap.into_sorted_vec() {
+        if tx.send(Ok(ranked.0.candidate)).await.is_err() {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// top-k 경계 힙에서 후보를 정렬하기 위한 래퍼다.
+struct RankedCandidate {
+    score: f32,
+    path: String,
+    candidate: SearchCandidatePayload,
+}
+
+impl RankedCandidate {
+    fn new(candidate: SearchCandidatePayload) -> Self {
+        Self {
+            score: candidate.score,
+            path: candidate.path.clone(),
+            candidate,
+        }
+    }
+}
+
+impl PartialEq for RankedCandidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for RankedCandidate {}
+
+impl PartialOrd for RankedCandidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RankedCandidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // 점수가 높을수록, 동점이면 path가 작을수록 상위로 취급한다.
+        self.score
+            .total_cmp(&other.score)
+            .then_with(|| other.path.cmp(&self.path))
+    }
+}
+
 fn to_candidate(
     page: PageNodeRecord,
-    parent_score_map: &HashMap<String, f32>,
-    decision_map: &HashMap<String, FilterDecision>,
+    decision: &FilterDecision,
+    fused_scores: &HashMap<String, f32>,
+    question: &str,
+    snippet_options: &SnippetOptions,
 ) -> Option<SearchCandidatePayload> {
-    let decision = decision_map.get(&page.node_id)?;
     if !decision.keep {
         return None;
     }
 
-    let score = parent_score_map
-        .get(&page.parent_node_id)
-        .copied()
-        .unwrap_or(0.0)
-        .clamp(0.0, 1.0);
+    let score = fused_scores.get(&page.node_id).copied().unwrap_or(0.0);
+    let snippet = snippet_options.crop_length.map(|crop_length| {
+        build_snippet(
+            &page.content,
+            question,
+            crop_length,
+            &snippet_options.highlight_pre_tag,
+            &snippet_options.highlight_post_tag,
+        )
+    });
 
     Some(SearchCandidatePayload {
         node_id: page.node_id,
@@ -175,51 +515,507 @@ fn to_candidate(
         content: page.content,
         image_url: page.image_url,
         reason: decision.reason.clone(),
+        snippet,
     })
 }
 
-fn validate_payload(payload: &SearchRequestPayload) -> CoreResult<()> {
+/// 스니펫 생성 옵션 묶음이다.
+#[derive(Clone)]
+pub(crate) struct SnippetOptions {
+    crop_length: Option<usize>,
+    highlight_pre_tag: String,
+    highlight_post_tag: String,
+}
+
+impl SnippetOptions {
+    pub(crate) fn from_request(payload: &SearchRequestPayload) -> Self {
+        Self {
+            crop_length: payload.crop_length,
+            highlight_pre_tag: payload.highlight_pre_tag.clone(),
+            highlight_post_tag: payload.highlight_post_tag.clone(),
+        }
+    }
+}
+
+/// 질의와 가장 관련 있는 구간을 `crop_length` 토큰으로 잘라 하이라이트한 미리보기를 만든다.
+///
+/// 용어 겹침이 최대인 창을 골라 단어 경계를 보존해 자르고, 잘린 경계에는 표식을 붙인다.
+fn build_snippet(
+    content: &str,
+    question: &str,
+    crop_length: usize,
+    pre_tag: &str,
+    post_tag: &str,
+) -> String {
+    let window = crop_length.max(1);
+    let terms = query_terms(question);
+    let tokens = content.split_whitespace().collect::<Vec<_>>();
+
+    if tokens.is_empty() {
+        return String::new();
+    }
+
+    // 용어 겹침이 가장 큰 창의 시작 위치를 고른다(동점 시 앞쪽 우선).
+    let mut best_start = 0usize;
+    if tokens.len() > window {
+        let mut best_score = -1i32;
+        for start in 0..=(tokens.len() - window) {
+            let score = tokens[start..start + window]
+                .iter()
+                .filter(|token| terms.contains(&normalize_token(token)))
+                .count() as i32;
+            if score > best_score {
+                best_score = score;
+                best_start = start;
+            }
+        }
+    }
+
+    let end = (best_start + window).min(tokens.len());
+    let highlighted = tokens[best_start..end]
+        .iter()
+        .map(|token| highlight_token(token, &terms, pre_tag, post_tag))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut snippet = String::new();
+    if best_start > 0 {
+        snippet.push_str(CROP_MARKER);
+        snippet.push(' ');
+    }
+    snippet.push_str(&highlighted);
+    if end < tokens.len() {
+        snippet.push(' ');
+        snippet.push_str(CROP_MARKER);
+    }
+    snippet
+}
+
+/// 질의를 소문자 정규화한 용어 집합으로 만든다.
+fn query_terms(question: &str) -> std::collections::HashSet<String> {
+    question
+        .split_whitespace()
+        .map(normalize_token)
+        .filter(|term| !term.is_empty())
+        .collect()
+}
+
+/// 토큰을 소문자로 바꾸고 양끝 비영숫자를 제거한다.
+fn normalize_token(token: &str) -> String {
+    token
+        .trim_matches(|ch: char| !ch.is_alphanumeric())
+        .to_lowercase()
+}
+
+/// 토큰이 질의 용어에 해당하면 태그로 감싼다(앞뒤 구두점은 보존).
+fn highlight_token(
+    token: &str,
+    terms: &std::collections::HashSet<String>,
+    pre_tag: &str,
+    post_tag: &str,
+) -> String {
+    if terms.contains(&normalize_token(token)) {
+        format!("{}{}{}", pre_tag, token, post_tag)
+    } else {
+        token.to_string()
+    }
+}
+
+/// summary(리스트 A)와 page(리스트 B) 벡터 순위를 RRF로 융합해
+/// 페이지별 융합 점수 맵을 만든다.
+///
+/// 각 리스트에서 항목의 1-기반 순위 `r`에 대해 `1/(k + r)`를 더한다.
+/// 페이지는 자신(리스트 B)과 부모 summary(리스트 A)가 등장한 리스트에서만 기여를 받는다.
+fn fuse_scores(
+    entry_records: &[SummaryNodeRecord],
+    expanded_pages: &[PageNodeRecord],
+    rrf_k: f32,
+) -> HashMap<String, f32> {
+    // 리스트 A: summary/entry 노드를 벡터 점수 내림차순으로 정렬한 순위.
+    let mut list_a = entry_records.iter().collect::<Vec<_>>();
+    list_a.sort_by(|left, right| {
+        right
+            .score
+            .total_cmp(&left.score)
+            .then_with(|| left.node_id.cmp(&right.node_id))
+    });
+    let summary_rank = list_a
+        .iter()
+        .enumerate()
+        .map(|(index, record)| (record.node_id.clone(), index + 1))
+        .collect::<HashMap<_, _>>();
+
+    // 리스트 B: page 노드를 자신의 벡터 점수 내림차순으로 정렬한 순위.
+    let mut list_b = expanded_pages.iter().collect::<Vec<_>>();
+    list_b.sort_by(|left, right| {
+        right
+            .score
+            .total_cmp(&left.score)
+            .then_with(|| left.node_id.cmp(&right.node_id))
+    });
+    let page_rank = list_b
+        .iter()
+        .enumerate()
+        .map(|(index, record)| (record.node_id.clone(), index + 1))
+        .collect::<HashMap<_, _>>();
+
+    let mut fused = HashMap::<String, f32>::with_capacity(expanded_pages.len());
+    for page in expanded_pages {
+        let mut score = 0.0f32;
+        if let Some(rank) = summary_rank.get(&page.parent_node_id) {
+            score += 1.0 / (rrf_k + *rank as f32);
+        }
+        if let Some(rank) = page_rank.get(&page.node_id) {
+            score += 1.0 / (rrf_k + *rank as f32);
+        }
+        fused.insert(page.node_id.clone(), score);
+    }
+    fused
+}
+
+pub(crate) fn validate_payload(payload: &SearchRequestPayload) -> CoreResult<()> {
+    let mut errors = Vec::<ValidationError>::new();
+
     if payload.job_id.trim().is_empty() {
-        return Err(CoreError::InvalidInput(
-            "job_id는 비어 있을 수 없습니다".to_string(),
+        errors.push(ValidationError::new(
+            "job_id",
+            ValidationCode::Empty,
+            "job_id는 비어 있을 수 없습니다",
         ));
     }
 
     if payload.question.trim().is_empty() {
-        return Err(CoreError::InvalidInput(
-            "question은 비어 있을 수 없습니다".to_string(),
+        errors.push(ValidationError::new(
+            "question",
+            ValidationCode::Empty,
+            "question은 비어 있을 수 없습니다",
         ));
     }
 
     if payload.query_embedding.is_empty() {
-        return Err(CoreError::InvalidInput(
-            "query_embedding은 최소 1개 이상이어야 합니다".to_string(),
+        errors.push(ValidationError::new(
+            "query_embedding",
+            ValidationCode::Empty,
+            "query_embedding은 최소 1개 이상이어야 합니다",
         ));
     }
 
     if payload.top_k == 0 {
-        return Err(CoreError::InvalidInput(
-            "top_k는 1 이상이어야 합니다".to_string(),
+        errors.push(ValidationError::new(
+            "top_k",
+            ValidationCode::OutOfRange,
+            "top_k는 1 이상이어야 합니다",
         ));
     }
 
     if payload.entry_limit == 0 {
-        return Err(CoreError::InvalidInput(
-            "entry_limit은 1 이상이어야 합니다".to_string(),
+        errors.push(ValidationError::new(
+            "entry_limit",
+            ValidationCode::OutOfRange,
+            "entry_limit은 1 이상이어야 합니다",
         ));
     }
 
     if payload.page_limit == 0 {
-        return Err(CoreError::InvalidInput(
-            "page_limit은 1 이상이어야 합니다".to_string(),
+        errors.push(ValidationError::new(
+            "page_limit",
+            ValidationCode::OutOfRange,
+            "page_limit은 1 이상이어야 합니다",
         ));
     }
 
     if payload.worker_concurrency == 0 {
-        return Err(CoreError::InvalidInput(
-            "worker_concurrency는 1 이상이어야 합니다".to_string(),
+        errors.push(ValidationError::new(
+            "worker_concurrency",
+            ValidationCode::OutOfRange,
+            "worker_concurrency는 1 이상이어야 합니다",
         ));
     }
 
-    Ok(())
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(CoreError::Validation(errors))
+    }
+}
+
+fn validate_batch_payload(payload: &BatchSearchRequestPayload) -> CoreResult<()> {
+    let mut errors = Vec::<ValidationError>::new();
+
+    if payload.subqueries.is_empty() {
+        errors.push(ValidationError::new(
+            "subqueries",
+            ValidationCode::Empty,
+            "subqueries는 최소 1개 이상이어야 합니다",
+        ));
+    }
+
+    for sub in &payload.subqueries {
+        if sub.job_id.trim().is_empty() {
+            errors.push(ValidationError::new(
+                "job_id",
+                ValidationCode::Empty,
+                "job_id는 비어 있을 수 없습니다",
+            ));
+        }
+        if sub.question.trim().is_empty() {
+            errors.push(ValidationError::new(
+                "question",
+                ValidationCode::Empty,
+                "question은 비어 있을 수 없습니다",
+            ));
+        }
+        if sub.query_embedding.is_empty() {
+            errors.push(ValidationError::new(
+                "query_embedding",
+                ValidationCode::Empty,
+                "query_embedding은 최소 1개 이상이어야 합니다",
+            ));
+        }
+        if sub.top_k == 0 {
+            errors.push(ValidationError::new(
+                "top_k",
+                ValidationCode::OutOfRange,
+                "top_k는 1 이상이어야 합니다",
+            ));
+        }
+    }
+
+    // 한 배치는 같은 풀/인덱스를 공유하므로, 하위 질의끼리 임베딩 차원이
+    // 어긋나면 이후 단계에서 pgvector 질의가 실패한다 — 여기서 먼저 잡는다.
+    if let Some(expected_dim) = payload
+        .subqueries
+        .iter()
+        .find(|sub| !sub.query_embedding.is_empty())
+        .map(|sub| sub.query_embedding.len())
+    {
+        for sub in &payload.subqueries {
+            if !sub.query_embedding.is_empty() && sub.query_embedding.len() != expected_dim {
+                errors.push(ValidationError::new(
+                    "query_embedding",
+                    ValidationCode::DimensionMismatch,
+                    format!(
+                        "배치 내 query_embedding 차원이 일치하지 않습니다: expected={}, actual={}, job_id={}",
+                        expected_dim,
+                        sub.query_embedding.len(),
+                        sub.job_id
+                    ),
+                ));
+            }
+        }
+    }
+
+    if payload.entry_limit == 0 {
+        errors.push(ValidationError::new(
+            "entry_limit",
+            ValidationCode::OutOfRange,
+            "entry_limit은 1 이상이어야 합니다",
+        ));
+    }
+
+    if payload.page_limit == 0 {
+        errors.push(ValidationError::new(
+            "page_limit",
+            ValidationCode::OutOfRange,
+            "page_limit은 1 이상이어야 합니다",
+        ));
+    }
+
+    if payload.worker_concurrency == 0 {
+        errors.push(ValidationError::new(
+            "worker_concurrency",
+            ValidationCode::OutOfRange,
+            "worker_concurrency는 1 이상이어야 합니다",
+        ));
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(CoreError::Validation(errors))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(node_id: &str, score: f32) -> SummaryNodeRecord {
+        SummaryNodeRecord {
+            node_id: node_id.to_string(),
+            path: format!("root.{}", node_id),
+            score,
+        }
+    }
+
+    fn page(node_id: &str, parent_node_id: &str, score: f32) -> PageNodeRecord {
+        PageNodeRecord {
+            node_id: node_id.to_string(),
+            parent_node_id: parent_node_id.to_string(),
+            path: format!("root.{}.{}", parent_node_id, node_id),
+            content: "내용".to_string(),
+            image_url: None,
+            score,
+        }
+    }
+
+    #[test]
+    fn fuse_scores_rewards_pages_whose_parent_and_own_rank_both_lead() {
+        let entries = vec![summary("s1", 0.9), summary("s2", 0.5)];
+        let pages = vec![
+            page("p1", "s1", 0.8),
+            page("p2", "s2", 0.95),
+            page("p3", "s1", 0.1),
+        ];
+
+        let fused = fuse_scores(&entries, &pages, 60.0);
+
+        // p1의 부모(s1)가 1위 summary이고 p1 자신은 2위 page(1위는 p2)이므로 두 기여를 모두 받는다.
+        let expected_p1 = 1.0 / (60.0 + 1.0) + 1.0 / (60.0 + 2.0);
+        assert!((fused[&"p1".to_string()] - expected_p1).abs() < 1e-6);
+
+        // p3도 같은 부모(s1)를 두지만 자신의 page 순위(3위)가 p1(2위)보다 낮으므로 p1이 더 높다.
+        assert!(fused[&"p1".to_string()] > fused[&"p3".to_string()]);
+    }
+
+    #[test]
+    fn fuse_scores_gives_zero_contribution_to_absent_rankings() {
+        let entries = vec![summary("s1", 0.9)];
+        let pages = vec![page("p1", "unknown-parent", 0.5)];
+
+        let fused = fuse_scores(&entries, &pages, 60.0);
+
+        // p1의 부모는 entries 리스트에 없으므로 자신의 page 랭킹 기여만 받는다.
+        let expected = 1.0 / (60.0 + 1.0);
+        assert!((fused[&"p1".to_string()] - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn build_snippet_crops_window_around_query_terms_and_marks_truncation() {
+        let content = "사과 바나나 고양이 강아지 코끼리 기린 질의어 단어";
+        let question = "질의어";
+
+        let snippet = build_snippet(content, question, 3, "<em>", "</em>");
+
+        assert_eq!(snippet, "… 코끼리 기린 <em>질의어</em> …");
+    }
+
+    #[test]
+    fn build_snippet_omits_crop_marker_when_window_covers_whole_content() {
+        let content = "강아지 고양이";
+        let question = "고양이";
+
+        let snippet = build_snippet(content, question, 5, "<em>", "</em>");
+
+        assert_eq!(snippet, "강아지 <em>고양이</em>");
+    }
+
+    #[test]
+    fn highlight_token_preserves_surrounding_punctuation() {
+        let terms = query_terms("고양이");
+
+        assert_eq!(
+            highlight_token("고양이,", &terms, "<em>", "</em>"),
+            "<em>고양이,</em>"
+        );
+        assert_eq!(highlight_token("강아지", &terms, "<em>", "</em>"), "강아지");
+    }
+
+    fn valid_request_payload() -> SearchRequestPayload {
+        SearchRequestPayload {
+            job_id: "job-1".to_string(),
+            question: "질의".to_string(),
+            query_embedding: vec![0.1, 0.2],
+            top_k: 5,
+            entry_limit: 10,
+            page_limit: 10,
+            worker_concurrency: 2,
+            rrf_k: DEFAULT_RRF_K,
+            crop_length: None,
+            highlight_pre_tag: default_highlight_pre_tag(),
+            highlight_post_tag: default_highlight_post_tag(),
+            postgres: PostgresConfigPayload {
+                dsn: "postgres://localhost/test".to_string(),
+                summary_table: "summary".to_string(),
+                page_table: "page".to_string(),
+                pool_min: 1,
+                pool_max: 1,
+                connect_timeout_ms: 1000,
+                statement_timeout_ms: 1000,
+            },
+            filter_http: FilterHttpConfigPayload {
+                url: "http://localhost".to_string(),
+                timeout_ms: 1000,
+                auth_token: None,
+                model: None,
+                max_attempts: 1,
+                base_delay_ms: 10,
+                breaker_threshold: 3,
+            },
+            metadata: None,
+        }
+    }
+
+    #[test]
+    fn validate_payload_accepts_a_fully_populated_request() {
+        assert!(validate_payload(&valid_request_payload()).is_ok());
+    }
+
+    #[test]
+    fn validate_payload_accumulates_every_violated_field_instead_of_failing_fast() {
+        let mut payload = valid_request_payload();
+        payload.job_id = "  ".to_string();
+        payload.query_embedding = Vec::new();
+        payload.top_k = 0;
+
+        let error = validate_payload(&payload).expect_err("빈 필드는 실패해야 한다");
+        let CoreError::Validation(errors) = error else {
+            panic!("ValidationError 배리언트를 기대했다");
+        };
+
+        let fields = errors.iter().map(|e| e.field).collect::<Vec<_>>();
+        assert!(fields.contains(&"job_id"));
+        assert!(fields.contains(&"query_embedding"));
+        assert!(fields.contains(&"top_k"));
+        assert_eq!(fields.len(), 3);
+    }
+
+    #[test]
+    fn validate_batch_payload_flags_inconsistent_embedding_dimensions_across_subqueries() {
+        let payload = BatchSearchRequestPayload {
+            subqueries: vec![
+                SubQueryPayload {
+                    job_id: "job-1".to_string(),
+                    question: "질의1".to_string(),
+                    query_embedding: vec![0.1, 0.2, 0.3],
+                    top_k: 5,
+                },
+                SubQueryPayload {
+                    job_id: "job-2".to_string(),
+                    question: "질의2".to_string(),
+                    query_embedding: vec![0.1, 0.2],
+                    top_k: 5,
+                },
+            ],
+            entry_limit: 10,
+            page_limit: 10,
+            worker_concurrency: 2,
+            rrf_k: DEFAULT_RRF_K,
+            crop_length: None,
+            highlight_pre_tag: default_highlight_pre_tag(),
+            highlight_post_tag: default_highlight_post_tag(),
+            postgres: valid_request_payload().postgres,
+            filter_http: valid_request_payload().filter_http,
+            metadata: None,
+        };
+
+        let error = validate_batch_payload(&payload).expect_err("차원 불일치는 실패해야 한다");
+        let CoreError::Validation(errors) = error else {
+            panic!("ValidationError 배리언트를 기대했다");
+        };
+
+        assert!(errors
+            .iter()
+            .any(|e| e.field == "query_embedding" && e.code == ValidationCode::DimensionMismatch));
+    }
 }
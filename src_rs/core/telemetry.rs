@@ -0,0 +1,40 @@
+// 목적:
+// - 핫 패스 관측을 위한 metrics 계측 이름과 초기화 훅을 제공한다.
+//
+// 설명:
+// - 저장소 질의/리랭크/필터 HTTP의 카운터·히스토그램·게이지 이름을 한곳에 모은다.
+// - 호스트 프로세스가 임의의 recorder를 등록할 수 있는 얇은 init 훅을 노출한다.
+//
+// 디자인 패턴:
+// - 파사드(Facade) + 상수 집약(Constant Aggregation).
+//
+// 참조:
+// - src_rs/index/postgres_repo.rs
+// - src_rs/core/filter_http.rs
+
+use metrics::Recorder;
+
+/// summary 벡터 검색 호출 수 카운터.
+pub const SUMMARY_SEARCH_TOTAL: &str = "summary_search_total";
+/// page 조회 호출 수 카운터.
+pub const PAGE_FETCH_TOTAL: &str = "page_fetch_total";
+/// upsert된 행 수 카운터.
+pub const UPSERT_ROWS_TOTAL: &str = "upsert_rows_total";
+
+/// search_summary_nodes 질의 지연(초) 히스토그램.
+pub const SUMMARY_SEARCH_LATENCY: &str = "summary_search_latency_seconds";
+/// fetch_pages_under_path 질의 지연(초) 히스토그램.
+pub const PAGE_FETCH_LATENCY: &str = "page_fetch_latency_seconds";
+/// filter_single HTTP 왕복 지연(초) 히스토그램.
+pub const FILTER_ROUNDTRIP_LATENCY: &str = "filter_roundtrip_latency_seconds";
+
+/// 진행 중인 필터 permit 게이지(획득 대 bounded 한도).
+pub const FILTER_INFLIGHT_PERMITS: &str = "filter_inflight_permits";
+
+/// 호스트 프로세스가 전역 metrics recorder를 등록하는 얇은 훅이다.
+pub fn init<R>(recorder: R) -> Result<(), metrics::SetRecorderError<R>>
+where
+    R: Recorder + Sync + 'static,
+{
+    metrics::set_global_recorder(recorder)
+}
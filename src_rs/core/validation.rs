@@ -0,0 +1,57 @@
+// 목적:
+// - 페이로드 검증 실패를 기계가 읽을 수 있는 구조로 표현한다.
+//
+// 설명:
+// - 필드별 오류를 코드/메시지와 함께 누적해 Python 호출자가 문자열 파싱 없이
+//   `error.code`/`error.field`로 분기할 수 있게 한다.
+// - deserr 스타일의 필드 오류 보고를 모사한다.
+//
+// 디자인 패턴:
+// - 누적 검증(Accumulating Validation) + 값 객체(Value Object).
+//
+// 참조:
+// - src_rs/core/search_pipeline.rs
+// - src_rs/core/errors.rs
+
+use serde::{Deserialize, Serialize};
+
+/// 검증 실패의 종류를 나타내는 기계 판독용 코드다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationCode {
+    /// 필수 값이 없음.
+    Missing,
+    /// 값이 비어 있음.
+    Empty,
+    /// 값이 허용 범위를 벗어남.
+    OutOfRange,
+    /// 차원(길이)이 기대와 불일치.
+    DimensionMismatch,
+    /// 지원하지 않는 연산.
+    UnknownOperation,
+}
+
+/// 단일 필드에 대한 검증 오류다.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationError {
+    pub field: &'static str,
+    pub code: ValidationCode,
+    pub message: String,
+}
+
+impl ValidationError {
+    pub fn new(field: &'static str, code: ValidationCode, message: impl Into<String>) -> Self {
+        Self {
+            field,
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+/// 검증 오류 목록을 JSON 배열 문자열로 직렬화한다.
+///
+/// 직렬화에 실패하면(일반적으로 불가능) 디버그 표현으로 대체한다.
+pub fn format_validation_errors(errors: &[ValidationError]) -> String {
+    serde_json::to_string(errors).unwrap_or_else(|_| format!("{:?}", errors))
+}
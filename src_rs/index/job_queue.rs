@@ -0,0 +1,270 @@
+// 목적:
+// - 적재 upsert를 내구성 있는 작업 큐로 비동기 처리한다.
+//
+// 설명:
+// - summary/page 레코드 배치를 JSON 작업으로 큐 테이블에 적재한다.
+// - 워커 루프가 `FOR UPDATE SKIP LOCKED`로 작업을 선점해 매칭되는 upsert를 호출한다.
+// - 실패 시 지수 백오프로 재적재하고, 최대 시도 초과 시 데드레터로 전환한다.
+//
+// 디자인 패턴:
+// - 푸시-작업/큐(Push-Job/Queue) + 워커 풀(Worker Pool).
+//
+// 참조:
+// - src_rs/index/postgres_repo.rs
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use sqlx::{PgPool, Row};
+
+use crate::core::errors::{CoreError, CoreResult};
+use crate::index::postgres_repo::{
+    IngestionPageNodeRecord, IngestionSummaryNodeRecord, PostgresRepository,
+};
+use crate::index::sql::validate_identifier;
+
+/// 데드레터 처리 전 최대 시도 횟수다.
+const DEFAULT_MAX_ATTEMPTS: i32 = 5;
+/// 지수 백오프의 밑(초). `base^attempts`로 다음 실행 시각을 늦춘다.
+const BACKOFF_BASE_SECS: i64 = 2;
+/// 한 번에 늦출 수 있는 최대 백오프(초)다.
+const BACKOFF_MAX_SECS: i64 = 900;
+/// 큐가 비었을 때 워커가 다시 폴링하기 전 대기 시간이다.
+const IDLE_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+const JOB_KIND_SUMMARY: &str = "upsert_summary";
+const JOB_KIND_PAGE: &str = "upsert_page";
+
+/// 큐에 적재할 수 있는 적재 배치 종류다.
+pub enum IngestionBatch {
+    Summary(Vec<IngestionSummaryNodeRecord>),
+    Page(Vec<IngestionPageNodeRecord>),
+}
+
+impl IngestionBatch {
+    fn kind(&self) -> &'static str {
+        match self {
+            IngestionBatch::Summary(_) => JOB_KIND_SUMMARY,
+            IngestionBatch::Page(_) => JOB_KIND_PAGE,
+        }
+    }
+
+    fn payload(&self) -> CoreResult<serde_json::Value> {
+        let value = match self {
+            IngestionBatch::Summary(rows) => serde_json::to_value(rows),
+            IngestionBatch::Page(rows) => serde_json::to_value(rows),
+        };
+        value.map_err(|error| CoreError::Serialization(format!("작업 직렬화 실패: {}", error)))
+    }
+}
+
+/// 적재 upsert를 위한 내구성 작업 큐다.
+pub struct IngestionJobQueue {
+    pool: PgPool,
+    repository: Arc<PostgresRepository>,
+    queue_table: String,
+    max_attempts: i32,
+}
+
+impl IngestionJobQueue {
+    /// 저장소의 연결 풀을 공유하는 큐를 만든다.
+    pub fn new(
+        repository: Arc<PostgresRepository>,
+        queue_table: &str,
+        max_attempts: Option<i32>,
+    ) -> CoreResult<Self> {
+        validate_identifier(queue_table, "queue_table")?;
+
+        Ok(Self {
+            pool: repository.pool().clone(),
+            repository,
+            queue_table: queue_table.to_string(),
+            max_attempts: max_attempts.unwrap_or(DEFAULT_MAX_ATTEMPTS).max(1),
+        })
+    }
+
+    /// 큐 테이블이 없으면 생성한다.
+    pub async fn ensure_queue_table(&self) -> CoreResult<()> {
+        let ddl = format!(
+            "CREATE TABLE IF NOT EXISTS {table} (\
+                 id BIGSERIAL PRIMARY KEY, \
+                 kind TEXT NOT NULL, \
+                 payload JSONB NOT NULL, \
+                 attempts INT NOT NULL DEFAULT 0, \
+                 status TEXT NOT NULL DEFAULT 'pending', \
+                 last_error TEXT, \
+                 next_run_at TIMESTAMPTZ NOT NULL DEFAULT NOW(), \
+                 created_at TIMESTAMPTZ NOT NULL DEFAULT NOW())",
+            table = self.queue_table
+        );
+
+        sqlx::query(&ddl)
+            .execute(&self.pool)
+            .await
+            .map_err(|error| CoreError::Db(format!("큐 테이블 생성 실패: {}", error)))?;
+
+        Ok(())
+    }
+
+    /// 적재 배치를 JSON 작업으로 큐에 적재하고 작업 id를 반환한다.
+    pub async fn enqueue_ingestion(&self, batch: &IngestionBatch) -> CoreResult<i64> {
+        let payload = batch.payload()?;
+        let sql = format!(
+            "INSERT INTO {table} (kind, payload, attempts, next_run_at) \
+             VALUES ($1, $2, 0, NOW()) RETURNING id",
+            table = self.queue_table
+        );
+
+        let row = sqlx::query(&sql)
+            .bind(batch.kind())
+            .bind(payload)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|error| CoreError::Db(format!("작업 적재 실패: {}", error)))?;
+
+        Ok(row.get::<i64, _>("id"))
+    }
+
+    /// `concurrency`개의 워커로 큐를 소비한다. 정상 동작 중에는 반환하지 않으며,
+    /// 워커의 큐 테이블 DB 작업(선점/삭제/갱신/커밋)이 실패하면 그 오류를 반환한다.
+    pub async fn run_worker(self: Arc<Self>, concurrency: usize) -> CoreResult<()> {
+        let bounded = concurrency.max(1);
+        let mut join_set = tokio::task::JoinSet::new();
+
+        for _ in 0..bounded {
+            let worker = self.clone();
+            join_set.spawn(async move { worker.worker_loop().await });
+        }
+
+        while let Some(joined) = join_set.join_next().await {
+            joined.map_err(|error| CoreError::Runtime(format!("워커 조인 실패: {}", error)))??;
+        }
+
+        Ok(())
+    }
+
+    async fn worker_loop(&self) -> CoreResult<()> {
+        loop {
+            if !self.claim_and_process().await? {
+                tokio::time::sleep(IDLE_POLL_INTERVAL).await;
+            }
+        }
+    }
+
+    /// 준비된 작업 하나를 선점해 처리한다. 처리할 작업이 없으면 false를 반환한다.
+    async fn claim_and_process(&self) -> CoreResult<bool> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|error| CoreError::Db(format!("작업 트랜잭션 시작 실패: {}", error)))?;
+
+        let claim_sql = format!(
+            "SELECT id, kind, payload, attempts FROM {table} \
+             WHERE status = 'pending' AND next_run_at <= NOW() \
+             ORDER BY next_run_at \
+             FOR UPDATE SKIP LOCKED LIMIT 1",
+            table = self.queue_table
+        );
+
+        let claimed = sqlx::query(&claim_sql)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|error| CoreError::Db(format!("작업 선점 실패: {}", error)))?;
+
+        let Some(row) = claimed else {
+            tx.rollback()
+                .await
+                .map_err(|error| CoreError::Db(format!("작업 트랜잭션 롤백 실패: {}", error)))?;
+            return Ok(false);
+        };
+
+        let id: i64 = row.get("id");
+        let kind: String = row.get("kind");
+        let payload: serde_json::Value = row.get("payload");
+        let attempts: i32 = row.get("attempts");
+
+        match self.process_payload(&kind, payload).await {
+            Ok(()) => {
+                let delete_sql = format!("DELETE FROM {table} WHERE id = $1", table = self.queue_table);
+                sqlx::query(&delete_sql)
+                    .bind(id)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|error| CoreError::Db(format!("완료 작업 삭제 실패: {}", error)))?;
+            }
+            Err(error) => {
+                let next_attempts = attempts + 1;
+                if next_attempts >= self.max_attempts {
+                    let dead_sql = format!(
+                        "UPDATE {table} SET status = 'dead', attempts = $2, last_error = $3 \
+                         WHERE id = $1",
+                        table = self.queue_table
+                    );
+                    sqlx::query(&dead_sql)
+                        .bind(id)
+                        .bind(next_attempts)
+                        .bind(error.to_string())
+                        .execute(&mut *tx)
+                        .await
+                        .map_err(|error| CoreError::Db(format!("데드레터 전환 실패: {}", error)))?;
+                } else {
+                    let backoff_secs = backoff_seconds(next_attempts);
+                    let retry_sql = format!(
+                        "UPDATE {table} SET attempts = $2, last_error = $3, \
+                         next_run_at = NOW() + make_interval(secs => $4) WHERE id = $1",
+                        table = self.queue_table
+                    );
+                    sqlx::query(&retry_sql)
+                        .bind(id)
+                        .bind(next_attempts)
+                        .bind(error.to_string())
+                        .bind(backoff_secs as f64)
+                        .execute(&mut *tx)
+                        .await
+                        .map_err(|error| CoreError::Db(format!("작업 재적재 실패: {}", error)))?;
+                }
+            }
+        }
+
+        tx.commit()
+            .await
+            .map_err(|error| CoreError::Db(format!("작업 트랜잭션 커밋 실패: {}", error)))?;
+
+        Ok(true)
+    }
+
+    async fn process_payload(&self, kind: &str, payload: serde_json::Value) -> CoreResult<()> {
+        match kind {
+            JOB_KIND_SUMMARY => {
+                let rows: Vec<IngestionSummaryNodeRecord> = serde_json::from_value(payload)
+                    .map_err(|error| {
+                        CoreError::Serialization(format!("summary 작업 역직렬화 실패: {}", error))
+                    })?;
+                self.repository.upsert_summary_nodes(&rows).await?;
+                Ok(())
+            }
+            JOB_KIND_PAGE => {
+                let rows: Vec<IngestionPageNodeRecord> = serde_json::from_value(payload)
+                    .map_err(|error| {
+                        CoreError::Serialization(format!("page 작업 역직렬화 실패: {}", error))
+                    })?;
+                self.repository.upsert_page_nodes(&rows).await?;
+                Ok(())
+            }
+            other => Err(CoreError::InvalidInput(format!(
+                "지원하지 않는 작업 종류입니다: {}",
+                other
+            ))),
+        }
+    }
+}
+
+/// `base^attempts`초 백오프를 상한으로 제한해 반환한다.
+fn backoff_seconds(attempts: i32) -> i64 {
+    let exponent = attempts.max(1) as u32;
+    BACKOFF_BASE_SECS
+        .checked_pow(exponent)
+        .unwrap_or(BACKOFF_MAX_SECS)
+        .min(BACKOFF_MAX_SECS)
+}
@@ -11,5 +11,6 @@
 // - src_rs/index/sql.rs
 // - src_rs/index/postgres_repo.rs
 
+pub mod job_queue;
 pub mod postgres_repo;
 pub mod sql;
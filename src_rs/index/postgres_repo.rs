@@ -12,12 +12,22 @@
 // - src_rs/index/sql.rs
 // - src_rs/core/search_pipeline.rs
 
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::time::Instant;
+
+use dashmap::DashSet;
+use metrics::{counter, histogram};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use sqlx::postgres::{PgPoolOptions, PgRow};
 use sqlx::{PgPool, Row};
 
 use crate::core::errors::{CoreError, CoreResult};
-use crate::index::sql::{to_pgvector_literal, validate_identifier};
+use crate::core::telemetry;
+use crate::index::sql::{
+    create_hnsw_index_ddl, to_pgvector_literal, validate_identifier, DistanceMetric,
+};
 
 #[derive(Debug, Clone)]
 pub struct SummaryNodeRecord {
@@ -33,9 +43,11 @@ pub struct PageNodeRecord {
     pub path: String,
     pub content: String,
     pub image_url: Option<String>,
+    /// 페이지 임베딩과 질의 벡터의 pgvector 유사도(0~1). 비벡터 조회에서는 0이다.
+    pub score: f32,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IngestionSummaryNodeRecord {
     pub node_id: String,
     pub document_id: String,
@@ -45,7 +57,7 @@ pub struct IngestionSummaryNodeRecord {
     pub metadata: Value,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IngestionPageNodeRecord {
     pub node_id: String,
     pub parent_node_id: String,
@@ -53,13 +65,28 @@ pub struct IngestionPageNodeRecord {
     pub path: String,
     pub content: String,
     pub image_url: Option<String>,
+    pub image_phash: Option<i64>,
     pub metadata: Value,
 }
 
+/// 매우 큰 배치에서도 메모리를 제한하기 위한 upsert 청크 크기다.
+const UPSERT_CHUNK_SIZE: usize = 500;
+
+/// HNSW 인덱스를 생성할 대상 테이블을 고른다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IndexTarget {
+    /// `summary_table`.
+    Summary,
+    /// `page_table`.
+    Page,
+}
+
 pub struct PostgresRepository {
     pool: PgPool,
     summary_table: String,
     page_table: String,
+    /// 노드 내용 지문 집합. 변경되지 않은 행의 재기록을 건너뛰기 위해 사용한다.
+    fingerprints: DashSet<u64>,
 }
 
 impl PostgresRepository {
@@ -100,27 +127,43 @@ impl PostgresRepository {
             pool,
             summary_table: summary_table.to_string(),
             page_table: page_table.to_string(),
+            fingerprints: DashSet::new(),
         })
     }
 
+    /// 내부 연결 풀을 공유한다(큐 워커 등 같은 크레이트 구성 요소에서 사용).
+    pub(crate) fn pool(&self) -> &PgPool {
+        &self.pool
+    }
+
+    /// 연결 풀을 닫아 모든 연결을 정리한다.
+    pub async fn close(&self) {
+        self.pool.close().await;
+    }
+
     pub async fn search_summary_nodes(
         &self,
         query_embedding: &[f32],
         limit: usize,
     ) -> CoreResult<Vec<SummaryNodeRecord>> {
         let vector_literal = to_pgvector_literal(query_embedding)?;
+        let operator = DistanceMetric::Cosine.operator();
         let sql = format!(
-            "SELECT node_id, path::text AS path, (1 - (embedding <=> $1::vector)) AS score \
-             FROM {} ORDER BY embedding <=> $1::vector LIMIT $2",
-            self.summary_table
+            "SELECT node_id, path::text AS path, (1 - (embedding {op} $1::vector)) AS score \
+             FROM {} ORDER BY embedding {op} $1::vector LIMIT $2",
+            self.summary_table,
+            op = operator
         );
 
+        let started = Instant::now();
         let rows = sqlx::query(&sql)
             .bind(vector_literal)
             .bind(limit as i64)
             .fetch_all(&self.pool)
             .await
             .map_err(|error| CoreError::Db(format!("summary 조회 실패: {}", error)))?;
+        histogram!(telemetry::SUMMARY_SEARCH_LATENCY).record(started.elapsed().as_secs_f64());
+        counter!(telemetry::SUMMARY_SEARCH_TOTAL).increment(1);
 
         rows.into_iter()
             .map(map_summary_row)
@@ -130,20 +173,62 @@ impl PostgresRepository {
     pub async fn fetch_pages_under_path(
         &self,
         path: &str,
+        query_embedding: &[f32],
         limit: usize,
     ) -> CoreResult<Vec<PageNodeRecord>> {
+        let vector_literal = to_pgvector_literal(query_embedding)?;
+        let operator = DistanceMetric::Cosine.operator();
         let sql = format!(
-            "SELECT node_id, parent_node_id, path::text AS path, content, image_url \
-             FROM {} WHERE path <@ $1::ltree ORDER BY path LIMIT $2",
-            self.page_table
+            "SELECT node_id, parent_node_id, path::text AS path, content, image_url, \
+             (1 - (embedding {op} $1::vector)) AS score \
+             FROM {} WHERE path <@ $2::ltree ORDER BY path LIMIT $3",
+            self.page_table,
+            op = operator
         );
 
+        let started = Instant::now();
         let rows = sqlx::query(&sql)
+            .bind(vector_literal)
             .bind(path)
             .bind(limit as i64)
             .fetch_all(&self.pool)
             .await
             .map_err(|error| CoreError::Db(format!("page 조회 실패: {}", error)))?;
+        histogram!(telemetry::PAGE_FETCH_LATENCY).record(started.elapsed().as_secs_f64());
+        counter!(telemetry::PAGE_FETCH_TOTAL).increment(1);
+
+        rows.into_iter()
+            .map(map_page_row)
+            .collect::<CoreResult<Vec<_>>>()
+    }
+
+    /// 지각 해시 해밍 거리가 임계값 이내인 페이지를 가까운 순으로 조회한다.
+    ///
+    /// XOR(`#`) 후 `bit(64)` 텍스트의 '1' 개수로 popcount를 계산해
+    /// `max_hamming` 이하인 페이지만 반환한다.
+    pub async fn fetch_pages_by_image_similarity(
+        &self,
+        phash: i64,
+        max_hamming: u32,
+        limit: usize,
+    ) -> CoreResult<Vec<PageNodeRecord>> {
+        let sql = format!(
+            "SELECT node_id, parent_node_id, path::text AS path, content, image_url, \
+             0.0::real AS score \
+             FROM {} WHERE image_phash IS NOT NULL \
+             AND length(replace(((image_phash # $1)::bit(64))::text, '0', '')) <= $2 \
+             ORDER BY length(replace(((image_phash # $1)::bit(64))::text, '0', '')) \
+             LIMIT $3",
+            self.page_table
+        );
+
+        let rows = sqlx::query(&sql)
+            .bind(phash)
+            .bind(max_hamming as i64)
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|error| CoreError::Db(format!("이미지 유사도 조회 실패: {}", error)))?;
 
         rows.into_iter()
             .map(map_page_row)
@@ -155,10 +240,12 @@ impl PostgresRepository {
             return Ok(0);
         }
 
+        // UNNEST 기반 단일 문으로 청크(최대 UPSERT_CHUNK_SIZE행)당 왕복을 한 번으로 줄인다.
         let sql = format!(
             "INSERT INTO {} \
              (node_id, document_id, path, summary_text, embedding, metadata, updated_at) \
-             VALUES ($1, $2, $3::ltree, $4, $5::vector, $6, NOW()) \
+             SELECT *, NOW() FROM unnest($1::text[], $2::text[], $3::ltree[], $4::text[], \
+             $5::vector[], $6::jsonb[]) \
              ON CONFLICT (node_id) DO UPDATE SET \
              document_id = EXCLUDED.document_id, \
              path = EXCLUDED.path, \
@@ -169,22 +256,59 @@ impl PostgresRepository {
             self.summary_table
         );
 
-        let mut affected = 0u64;
+        // 변경되지 않은 행은 재기록하지 않고 updated_at만 갱신한다.
+        let mut write_rows = Vec::<&IngestionSummaryNodeRecord>::new();
+        let mut write_hashes = Vec::<u64>::new();
+        let mut touch_ids = Vec::<String>::new();
         for row in rows {
-            let vector_literal = to_pgvector_literal(&row.embedding)?;
+            let fingerprint = summary_fingerprint(row);
+            if self.fingerprints.contains(&fingerprint) {
+                touch_ids.push(row.node_id.clone());
+            } else {
+                write_rows.push(row);
+                write_hashes.push(fingerprint);
+            }
+        }
+
+        let mut affected = 0u64;
+        for chunk in write_rows.chunks(UPSERT_CHUNK_SIZE) {
+            let mut node_ids = Vec::with_capacity(chunk.len());
+            let mut document_ids = Vec::with_capacity(chunk.len());
+            let mut paths = Vec::with_capacity(chunk.len());
+            let mut summary_texts = Vec::with_capacity(chunk.len());
+            let mut embeddings = Vec::with_capacity(chunk.len());
+            let mut metadata = Vec::with_capacity(chunk.len());
+
+            for row in chunk {
+                node_ids.push(row.node_id.clone());
+                document_ids.push(row.document_id.clone());
+                paths.push(row.path.clone());
+                summary_texts.push(row.summary_text.clone());
+                embeddings.push(to_pgvector_literal(&row.embedding)?);
+                metadata.push(row.metadata.clone());
+            }
+
             let result = sqlx::query(&sql)
-                .bind(&row.node_id)
-                .bind(&row.document_id)
-                .bind(&row.path)
-                .bind(&row.summary_text)
-                .bind(vector_literal)
-                .bind(&row.metadata)
+                .bind(&node_ids)
+                .bind(&document_ids)
+                .bind(&paths)
+                .bind(&summary_texts)
+                .bind(&embeddings)
+                .bind(&metadata)
                 .execute(&self.pool)
                 .await
                 .map_err(|error| CoreError::Db(format!("summary upsert 실패: {}", error)))?;
             affected = affected.saturating_add(result.rows_affected());
         }
 
+        // 기록에 성공한 지문만 캐시에 등록한다.
+        for fingerprint in write_hashes {
+            self.fingerprints.insert(fingerprint);
+        }
+
+        counter!(telemetry::UPSERT_ROWS_TOTAL).increment(affected);
+        self.touch_updated_at(&self.summary_table, &touch_ids).await?;
+
         Ok(affected)
     }
 
@@ -193,40 +317,107 @@ impl PostgresRepository {
             return Ok(0);
         }
 
+        // UNNEST 기반 단일 문으로 청크(최대 UPSERT_CHUNK_SIZE행)당 왕복을 한 번으로 줄인다.
         let sql = format!(
             "INSERT INTO {} \
-             (node_id, parent_node_id, document_id, path, content, image_url, metadata, updated_at) \
-             VALUES ($1, $2, $3, $4::ltree, $5, $6, $7, NOW()) \
+             (node_id, parent_node_id, document_id, path, content, image_url, image_phash, \
+             metadata, updated_at) \
+             SELECT *, NOW() FROM unnest($1::text[], $2::text[], $3::text[], $4::ltree[], \
+             $5::text[], $6::text[], $7::bigint[], $8::jsonb[]) \
              ON CONFLICT (node_id) DO UPDATE SET \
              parent_node_id = EXCLUDED.parent_node_id, \
              document_id = EXCLUDED.document_id, \
              path = EXCLUDED.path, \
              content = EXCLUDED.content, \
              image_url = EXCLUDED.image_url, \
+             image_phash = EXCLUDED.image_phash, \
              metadata = EXCLUDED.metadata, \
              updated_at = NOW()",
             self.page_table
         );
 
-        let mut affected = 0u64;
+        // 변경되지 않은 행은 재기록하지 않고 updated_at만 갱신한다.
+        let mut write_rows = Vec::<&IngestionPageNodeRecord>::new();
+        let mut write_hashes = Vec::<u64>::new();
+        let mut touch_ids = Vec::<String>::new();
         for row in rows {
+            let fingerprint = page_fingerprint(row);
+            if self.fingerprints.contains(&fingerprint) {
+                touch_ids.push(row.node_id.clone());
+            } else {
+                write_rows.push(row);
+                write_hashes.push(fingerprint);
+            }
+        }
+
+        let mut affected = 0u64;
+        for chunk in write_rows.chunks(UPSERT_CHUNK_SIZE) {
+            let mut node_ids = Vec::with_capacity(chunk.len());
+            let mut parent_node_ids = Vec::with_capacity(chunk.len());
+            let mut document_ids = Vec::with_capacity(chunk.len());
+            let mut paths = Vec::with_capacity(chunk.len());
+            let mut contents = Vec::with_capacity(chunk.len());
+            let mut image_urls = Vec::<Option<String>>::with_capacity(chunk.len());
+            let mut image_phashes = Vec::<Option<i64>>::with_capacity(chunk.len());
+            let mut metadata = Vec::with_capacity(chunk.len());
+
+            for row in chunk {
+                node_ids.push(row.node_id.clone());
+                parent_node_ids.push(row.parent_node_id.clone());
+                document_ids.push(row.document_id.clone());
+                paths.push(row.path.clone());
+                contents.push(row.content.clone());
+                image_urls.push(row.image_url.clone());
+                image_phashes.push(row.image_phash);
+                metadata.push(row.metadata.clone());
+            }
+
             let result = sqlx::query(&sql)
-                .bind(&row.node_id)
-                .bind(&row.parent_node_id)
-                .bind(&row.document_id)
-                .bind(&row.path)
-                .bind(&row.content)
-                .bind(&row.image_url)
-                .bind(&row.metadata)
+                .bind(&node_ids)
+                .bind(&parent_node_ids)
+                .bind(&document_ids)
+                .bind(&paths)
+                .bind(&contents)
+                .bind(&image_urls)
+                .bind(&image_phashes)
+                .bind(&metadata)
                 .execute(&self.pool)
                 .await
                 .map_err(|error| CoreError::Db(format!("page upsert 실패: {}", error)))?;
             affected = affected.saturating_add(result.rows_affected());
         }
 
+        // 기록에 성공한 지문만 캐시에 등록한다.
+        for fingerprint in write_hashes {
+            self.fingerprints.insert(fingerprint);
+        }
+
+        counter!(telemetry::UPSERT_ROWS_TOTAL).increment(affected);
+        self.touch_updated_at(&self.page_table, &touch_ids).await?;
+
         Ok(affected)
     }
 
+    /// 내용이 바뀌지 않아 건너뛴 행들의 updated_at을 한 번의 UPDATE로 갱신한다.
+    async fn touch_updated_at(&self, table: &str, node_ids: &[String]) -> CoreResult<u64> {
+        if node_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let sql = format!(
+            "UPDATE {} SET updated_at = NOW() WHERE node_id = ANY($1::text[])",
+            table
+        );
+
+        let result = sqlx::query(&sql)
+            .bind(node_ids)
+            .execute(&self.pool)
+            .await
+            .map_err(|error| CoreError::Db(format!("updated_at 갱신 실패: {}", error)))?;
+
+        Ok(result.rows_affected())
+    }
+
     pub async fn touch_summary_nodes(&self, document_id: &str) -> CoreResult<u64> {
         let sql = format!(
             "UPDATE {} SET updated_at = NOW() WHERE document_id = $1",
@@ -241,6 +432,66 @@ impl PostgresRepository {
 
         Ok(result.rows_affected())
     }
+
+    /// 지정한 테이블/컬럼에 pgvector HNSW 인덱스를 생성한다.
+    ///
+    /// 기본 테이블/컬럼/메트릭을 가정하지 않고, summary/page 어느 쪽 임베딩
+    /// 컬럼이든 적절히 인덱싱된 상태로 만들고 질의할 수 있게 한다.
+    pub async fn create_hnsw_index(
+        &self,
+        target: IndexTarget,
+        index_name: &str,
+        column: &str,
+        metric: DistanceMetric,
+        m: u32,
+        ef_construction: u32,
+    ) -> CoreResult<()> {
+        let table = match target {
+            IndexTarget::Summary => &self.summary_table,
+            IndexTarget::Page => &self.page_table,
+        };
+        let ddl = create_hnsw_index_ddl(index_name, table, column, metric, m, ef_construction)?;
+
+        sqlx::query(&ddl)
+            .execute(&self.pool)
+            .await
+            .map_err(|error| CoreError::Db(format!("HNSW 인덱스 생성 실패: {}", error)))?;
+
+        Ok(())
+    }
+}
+
+/// summary 노드의 내용 지문을 계산한다.
+///
+/// `document_id`도 포함해, 내용은 그대로인데 재소속(document_id 변경)만 일어난
+/// 행이 지문 일치로 오인되어 건너뛰지 않도록 한다.
+fn summary_fingerprint(row: &IngestionSummaryNodeRecord) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    row.node_id.hash(&mut hasher);
+    row.document_id.hash(&mut hasher);
+    row.path.hash(&mut hasher);
+    row.summary_text.hash(&mut hasher);
+    for value in &row.embedding {
+        value.to_bits().hash(&mut hasher);
+    }
+    row.metadata.to_string().hash(&mut hasher);
+    hasher.finish()
+}
+
+/// page 노드의 내용 지문을 계산한다.
+///
+/// `parent_node_id`/`document_id`도 포함해, 내용은 그대로인데 재소속(부모 변경,
+/// document_id 변경)만 일어난 행이 지문 일치로 오인되어 건너뛰지 않도록 한다.
+fn page_fingerprint(row: &IngestionPageNodeRecord) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    row.node_id.hash(&mut hasher);
+    row.parent_node_id.hash(&mut hasher);
+    row.document_id.hash(&mut hasher);
+    row.path.hash(&mut hasher);
+    row.content.hash(&mut hasher);
+    row.image_url.hash(&mut hasher);
+    row.metadata.to_string().hash(&mut hasher);
+    hasher.finish()
 }
 
 fn map_summary_row(row: PgRow) -> CoreResult<SummaryNodeRecord> {
@@ -277,6 +528,9 @@ fn map_page_row(row: PgRow) -> CoreResult<PageNodeRecord> {
     let image_url = row
         .try_get::<Option<String>, _>("image_url")
         .map_err(|error| CoreError::Db(format!("page.image_url 파싱 실패: {}", error)))?;
+    let score = row
+        .try_get::<f32, _>("score")
+        .map_err(|error| CoreError::Db(format!("page.score 파싱 실패: {}", error)))?;
 
     Ok(PageNodeRecord {
         node_id,
@@ -284,5 +538,78 @@ fn map_page_row(row: PgRow) -> CoreResult<PageNodeRecord> {
         path,
         content,
         image_url,
+        score: score.clamp(0.0, 1.0),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary_row() -> IngestionSummaryNodeRecord {
+        IngestionSummaryNodeRecord {
+            node_id: "n1".to_string(),
+            document_id: "doc1".to_string(),
+            path: "root.n1".to_string(),
+            summary_text: "요약".to_string(),
+            embedding: vec![0.1, 0.2, 0.3],
+            metadata: Value::Null,
+        }
+    }
+
+    fn page_row() -> IngestionPageNodeRecord {
+        IngestionPageNodeRecord {
+            node_id: "n1".to_string(),
+            parent_node_id: "parent1".to_string(),
+            document_id: "doc1".to_string(),
+            path: "root.n1.p1".to_string(),
+            content: "내용".to_string(),
+            image_url: None,
+            image_phash: None,
+            metadata: Value::Null,
+        }
+    }
+
+    #[test]
+    fn summary_fingerprint_changes_when_document_id_changes() {
+        let original = summary_row();
+        let mut reparented = original.clone();
+        reparented.document_id = "doc2".to_string();
+
+        assert_ne!(
+            summary_fingerprint(&original),
+            summary_fingerprint(&reparented)
+        );
+    }
+
+    #[test]
+    fn summary_fingerprint_is_stable_for_identical_rows() {
+        assert_eq!(
+            summary_fingerprint(&summary_row()),
+            summary_fingerprint(&summary_row())
+        );
+    }
+
+    #[test]
+    fn page_fingerprint_changes_when_parent_node_id_changes() {
+        let original = page_row();
+        let mut reparented = original.clone();
+        reparented.parent_node_id = "parent2".to_string();
+
+        assert_ne!(page_fingerprint(&original), page_fingerprint(&reparented));
+    }
+
+    #[test]
+    fn page_fingerprint_changes_when_document_id_changes() {
+        let original = page_row();
+        let mut moved = original.clone();
+        moved.document_id = "doc2".to_string();
+
+        assert_ne!(page_fingerprint(&original), page_fingerprint(&moved));
+    }
+
+    #[test]
+    fn page_fingerprint_is_stable_for_identical_rows() {
+        assert_eq!(page_fingerprint(&page_row()), page_fingerprint(&page_row()));
+    }
+}
@@ -50,3 +50,98 @@ pub fn to_pgvector_literal(values: &[f32]) -> CoreResult<String> {
 
     Ok(format!("[{}]", parts.join(",")))
 }
+
+/// pgvector 거리 연산자를 나타내는 열거형이다.
+///
+/// ORDER BY 절과 HNSW 인덱스 연산자 클래스를 선택할 때 사용한다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DistanceMetric {
+    /// 코사인 거리(`<=>`).
+    Cosine,
+    /// 유클리드(L2) 거리(`<->`).
+    L2,
+    /// 음의 내적(`<#>`).
+    InnerProduct,
+}
+
+impl DistanceMetric {
+    /// ORDER BY에 사용할 pgvector 거리 연산자를 반환한다.
+    pub fn operator(&self) -> &'static str {
+        match self {
+            DistanceMetric::Cosine => "<=>",
+            DistanceMetric::L2 => "<->",
+            DistanceMetric::InnerProduct => "<#>",
+        }
+    }
+
+    /// HNSW 인덱스에 사용할 pgvector 연산자 클래스를 반환한다.
+    pub fn vector_ops(&self) -> &'static str {
+        match self {
+            DistanceMetric::Cosine => "vector_cosine_ops",
+            DistanceMetric::L2 => "vector_l2_ops",
+            DistanceMetric::InnerProduct => "vector_ip_ops",
+        }
+    }
+}
+
+/// 반정밀도(half-precision) 저장을 위한 halfvec 리터럴을 생성한다.
+pub fn to_halfvec_literal(values: &[f32]) -> CoreResult<String> {
+    if values.is_empty() {
+        return Err(CoreError::InvalidInput(
+            "벡터는 최소 1개 이상의 값을 가져야 합니다".to_string(),
+        ));
+    }
+
+    // half precision은 유효 숫자가 적으므로 소수 4자리로 축약한다.
+    let parts = values
+        .iter()
+        .map(|value| format!("{:.4}", value))
+        .collect::<Vec<_>>();
+
+    Ok(format!("[{}]", parts.join(",")))
+}
+
+/// 이진 양자화(binary quantization) 저장을 위한 bit 리터럴을 생성한다.
+///
+/// 각 성분이 0보다 크면 1, 아니면 0으로 부호화해 저장 공간을 줄인다.
+pub fn to_bit_literal(values: &[f32]) -> CoreResult<String> {
+    if values.is_empty() {
+        return Err(CoreError::InvalidInput(
+            "벡터는 최소 1개 이상의 값을 가져야 합니다".to_string(),
+        ));
+    }
+
+    let bits = values
+        .iter()
+        .map(|value| if *value > 0.0 { '1' } else { '0' })
+        .collect::<String>();
+
+    Ok(bits)
+}
+
+/// pgvector HNSW 인덱스를 생성하는 DDL을 만든다.
+///
+/// 테이블/컬럼 식별자는 `validate_identifier`를 통과시켜 SQL 주입을 방지한다.
+pub fn create_hnsw_index_ddl(
+    index_name: &str,
+    table: &str,
+    column: &str,
+    metric: DistanceMetric,
+    m: u32,
+    ef_construction: u32,
+) -> CoreResult<String> {
+    validate_identifier(index_name, "hnsw.index_name")?;
+    validate_identifier(table, "hnsw.table")?;
+    validate_identifier(column, "hnsw.column")?;
+
+    Ok(format!(
+        "CREATE INDEX IF NOT EXISTS {index} ON {table} \
+         USING hnsw ({column} {ops}) WITH (m = {m}, ef_construction = {ef})",
+        index = index_name,
+        table = table,
+        column = column,
+        ops = metric.vector_ops(),
+        m = m.max(1),
+        ef = ef_construction.max(1),
+    ))
+}
@@ -22,12 +22,14 @@ pub mod core;
 pub mod index;
 pub mod math;
 
-use api::ingestion_bridge::PyIngestionBridge;
+use api::engine_bridge::PySearchEngine;
+use api::errors::PyValidationError;
 use api::search_bridge::PySearchBridge;
 
 #[pymodule]
 fn _vtree_search(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PySearchBridge>()?;
-    m.add_class::<PyIngestionBridge>()?;
+    m.add_class::<PySearchEngine>()?;
+    m.add("ValidationError", m.py().get_type::<PyValidationError>())?;
     Ok(())
 }
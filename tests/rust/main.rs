@@ -4,6 +4,7 @@ use _svve_core::core::pipeline;
 use _svve_core::core::voting;
 use _svve_core::math::linalg;
 use _svve_core::vdb::adapter::{DocVector, ScoredDoc, VdbAdapter};
+use _svve_core::vdb::hnsw::{HnswIndex, DEFAULT_EF, DEFAULT_EF_CONSTRUCTION, DEFAULT_M};
 use _svve_core::vdb::query;
 
 struct InMemoryVdb {
@@ -112,21 +113,164 @@ fn voting_rule_keeps_strong_or_weak_candidates_only() {
         vec![(40, 0.99)],
     ];
 
-    let merged = voting::merge_segment_results(&segments);
+    let merged = voting::merge_segment_results(&segments, query::RRF_K);
     let survivors = voting::select_survivor_ids(&merged, 5);
 
     assert_eq!(survivors, vec![10]);
 }
 
+#[test]
+fn rrf_fuse_ranks_documents_present_in_more_lists_higher() {
+    let lists = vec![
+        vec![(1, 0.9), (2, 0.8), (3, 0.1)],
+        vec![(2, 0.95), (1, 0.7)],
+        vec![(2, 0.5)],
+    ];
+
+    let fused = voting::rrf_fuse(&lists, query::RRF_K);
+
+    // 문서 2는 세 리스트 모두에 등장하므로 최상위여야 한다.
+    assert_eq!(fused.first().expect("융합 결과가 필요합니다").0, 2);
+}
+
 #[test]
 fn fixed_pipeline_returns_requested_top_k_when_available() {
     let adapter = fixture_adapter();
 
     let query = vec![1.0, 0.0, 0.0, 0.0];
-    let (ids, scores) =
-        pipeline::execute_search(&adapter, &query, 3).expect("검색이 성공해야 합니다");
+    let (ids, scores, explanations) = pipeline::execute_search(
+        &adapter,
+        &query,
+        3,
+        1.0,
+        None,
+        query::RRF_K,
+        query::SURVIVOR_COUNT,
+        false,
+    )
+    .expect("검색이 성공해야 합니다");
 
     assert_eq!(ids.len(), 3);
     assert_eq!(scores.len(), 3);
     assert_eq!(ids[0], 1);
+    assert!(explanations.is_none());
+}
+
+#[test]
+fn explain_flag_returns_score_details_for_each_hit() {
+    let adapter = fixture_adapter();
+
+    let query = vec![1.0, 0.0, 0.0, 0.0];
+    let (ids, _scores, explanations) = pipeline::execute_search(
+        &adapter,
+        &query,
+        3,
+        1.0,
+        None,
+        query::RRF_K,
+        query::SURVIVOR_COUNT,
+        true,
+    )
+    .expect("검색이 성공해야 합니다");
+
+    let details = explanations.expect("explain=true면 설명이 반환되어야 합니다");
+    assert_eq!(details.len(), ids.len());
+    assert_eq!(details[0].doc_id, ids[0]);
+    assert!(details[0].survived, "상위 문서는 생존 후보여야 합니다");
+}
+
+struct KeywordBoostVdb {
+    inner: InMemoryVdb,
+    keyword_hits: Vec<ScoredDoc>,
+}
+
+impl VdbAdapter for KeywordBoostVdb {
+    fn dim(&self) -> usize {
+        self.inner.dim()
+    }
+
+    fn search(&self, query: &[f32], limit: usize) -> Result<Vec<ScoredDoc>, String> {
+        self.inner.search(query, limit)
+    }
+
+    fn fetch_vectors(&self, doc_ids: &[u32]) -> Result<Vec<DocVector>, String> {
+        self.inner.fetch_vectors(doc_ids)
+    }
+
+    fn keyword_search(&self, _terms: &str, limit: usize) -> Result<Vec<ScoredDoc>, String> {
+        Ok(self.keyword_hits.iter().take(limit).copied().collect())
+    }
+}
+
+#[test]
+fn hybrid_search_surfaces_keyword_channel_hits() {
+    let adapter = KeywordBoostVdb {
+        inner: fixture_adapter(),
+        // 벡터 채널이 선호하지 않는 문서(5)를 키워드 채널이 강하게 밀어준다.
+        keyword_hits: vec![(5, 10.0), (1, 0.1)],
+    };
+
+    let query = vec![1.0, 0.0, 0.0, 0.0];
+    let (ids, _scores, _explanations) = pipeline::execute_search(
+        &adapter,
+        &query,
+        3,
+        0.2,
+        Some("keyword"),
+        query::RRF_K,
+        query::SURVIVOR_COUNT,
+        false,
+    )
+    .expect("하이브리드 검색이 성공해야 합니다");
+
+    assert!(ids.contains(&5), "키워드 채널 상위 문서가 결과에 포함되어야 합니다");
+}
+
+#[test]
+fn hnsw_index_returns_nearest_neighbor_first() {
+    let mut index = HnswIndex::new(4, DEFAULT_M, DEFAULT_EF_CONSTRUCTION, DEFAULT_EF);
+    for (id, vector) in [
+        (1u32, vec![1.0, 0.0, 0.0, 0.0]),
+        (2, vec![0.9, 0.1, 0.0, 0.0]),
+        (3, vec![0.0, 1.0, 0.0, 0.0]),
+        (4, vec![0.0, 0.0, 1.0, 0.0]),
+        (5, vec![0.0, 0.0, 0.0, 1.0]),
+    ] {
+        index.insert(id, vector).expect("삽입이 성공해야 합니다");
+    }
+
+    let hits = index
+        .search(&[1.0, 0.0, 0.0, 0.0], 3)
+        .expect("검색이 성공해야 합니다");
+
+    assert_eq!(hits.first().expect("결과가 필요합니다").0, 1);
+    assert!(hits.len() <= 3);
+}
+
+#[test]
+fn hnsw_index_drives_the_full_pipeline() {
+    let mut index = HnswIndex::new(4, DEFAULT_M, DEFAULT_EF_CONSTRUCTION, DEFAULT_EF);
+    for (id, vector) in [
+        (1u32, vec![1.0, 0.0, 0.0, 0.0]),
+        (2, vec![0.9, 0.1, 0.0, 0.0]),
+        (3, vec![0.7, 0.2, 0.1, 0.0]),
+        (4, vec![0.0, 1.0, 0.0, 0.0]),
+        (5, vec![0.0, 0.0, 1.0, 0.0]),
+    ] {
+        index.insert(id, vector).expect("삽입이 성공해야 합니다");
+    }
+
+    let (ids, _scores, _explain) = pipeline::execute_search(
+        &index,
+        &[1.0, 0.0, 0.0, 0.0],
+        3,
+        1.0,
+        None,
+        query::RRF_K,
+        query::SURVIVOR_COUNT,
+        false,
+    )
+    .expect("파이프라인 검색이 성공해야 합니다");
+
+    assert_eq!(ids.first().copied(), Some(1));
 }